@@ -0,0 +1,252 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+use crate::error::AocError;
+use crate::input::Source;
+use crate::runner::{Part, DAYS};
+
+/// Default location of the answer registry.
+pub const ANSWERS_PATH: &str = "answers/answers.txt";
+
+/// Known answers, keyed by day, part and input name.
+///
+/// The registry is a plain text file with one `day/part/input = answer` entry
+/// per line, e.g. `07/2/day07-example = 24933642`. The input name is the file
+/// stem of the input in the input directory. Blank lines and lines starting
+/// with `#` are ignored.
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: BTreeMap<(u8, Part, String), String>,
+}
+
+impl Answers {
+    pub fn get(&self, day: u8, part: Part, input: &str) -> Option<&str> {
+        self.answers
+            .get(&(day, part, input.to_string()))
+            .map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, input: &str, answer: &str) {
+        self.answers
+            .insert((day, part, input.to_string()), answer.to_string());
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (u8, Part, &str, &str)> {
+        self.answers
+            .iter()
+            .map(|((day, part, input), answer)| (*day, *part, input.as_str(), answer.as_str()))
+    }
+
+    pub fn load(path: &Path) -> Result<Answers, AocError> {
+        let contents = std::fs::read_to_string(path).map_err(|source| AocError::Io {
+            path: path.display().to_string(),
+            source,
+        })?;
+        contents.parse()
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let malformed = |reason: &str| AocError::Malformed {
+                line: i + 1,
+                reason: reason.to_string(),
+            };
+            let (key, answer) = line
+                .split_once('=')
+                .ok_or_else(|| malformed("expected `day/part/input = answer`"))?;
+            let mut fields = key.trim().split('/');
+            let (Some(day), Some(part), Some(input), None) =
+                (fields.next(), fields.next(), fields.next(), fields.next())
+            else {
+                return Err(malformed("expected the key to be `day/part/input`"));
+            };
+            let day: u8 = day.parse().map_err(|_| malformed("invalid day"))?;
+            let part: Part = part.parse().map_err(|_| malformed("invalid part"))?;
+            answers.insert(day, part, input, answer.trim());
+        }
+        Ok(answers)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String, actual: String },
+    /// The input exists but there is no known answer for it.
+    MissingAnswer { actual: String },
+    /// There is a known answer but its input is not available.
+    MissingInput { expected: String },
+    Error(String),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail { .. } => write!(f, "FAIL"),
+            Status::MissingAnswer { .. } | Status::MissingInput { .. } => write!(f, "missing"),
+            Status::Error(_) => write!(f, "ERROR"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+    pub status: Status,
+}
+
+impl Check {
+    pub fn failed(&self) -> bool {
+        matches!(self.status, Status::Fail { .. } | Status::Error(_))
+    }
+}
+
+/// Day an input file belongs to, judging by its name: `dayNN` or `dayNN-*`.
+fn input_day(stem: &str) -> Option<u8> {
+    let rest = stem.strip_prefix("day")?;
+    let day = rest.get(..2).unwrap_or(rest);
+    let suffix = &rest[day.len()..];
+    if suffix.is_empty() || suffix.starts_with('-') {
+        day.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Lists the inputs in `dir` as `(day, input name)` pairs.
+pub fn stored_inputs(dir: &Path) -> Result<Vec<(u8, String)>, AocError> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(source) => {
+            return Err(AocError::Io {
+                path: dir.display().to_string(),
+                source,
+            })
+        }
+    };
+    let mut inputs: Vec<(u8, String)> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .filter_map(|path| {
+            let stem = path.file_stem()?.to_str()?.to_string();
+            Some((input_day(&stem)?, stem))
+        })
+        .collect();
+    inputs.sort();
+    Ok(inputs)
+}
+
+/// Runs every solver on every input in `dir` and compares the results with
+/// the known answers.
+pub fn verify(answers: &Answers, dir: &Path) -> Result<Vec<Check>, AocError> {
+    let inputs = stored_inputs(dir)?;
+    let mut checks = vec![];
+    for (day_number, input_name) in &inputs {
+        let Some(day) = DAYS.iter().find(|d| d.day == *day_number) else {
+            continue;
+        };
+        let report = Source::Path(dir.join(format!("{}.txt", input_name)))
+            .read()
+            .and_then(|input| (day.run)(&input, &[Part::One, Part::Two]));
+        for part in [Part::One, Part::Two] {
+            let value = match &report {
+                Ok(report) => {
+                    let answer = report.answers.iter().find(|a| a.part == part).unwrap();
+                    answer.value.as_ref().map(String::clone).map_err(|e| e.to_string())
+                }
+                Err(e) => Err(e.to_string()),
+            };
+            let status = match (answers.get(*day_number, part, input_name), value) {
+                (_, Err(e)) => Status::Error(e),
+                (None, Ok(actual)) => Status::MissingAnswer { actual },
+                (Some(expected), Ok(actual)) if expected == actual => Status::Pass,
+                (Some(expected), Ok(actual)) => Status::Fail {
+                    expected: expected.to_string(),
+                    actual,
+                },
+            };
+            checks.push(Check {
+                day: *day_number,
+                part,
+                input: input_name.clone(),
+                status,
+            });
+        }
+    }
+    for (day, part, input, expected) in answers.iter() {
+        if !inputs.iter().any(|(d, name)| *d == day && name == input) {
+            checks.push(Check {
+                day,
+                part,
+                input: input.to_string(),
+                status: Status::MissingInput {
+                    expected: expected.to_string(),
+                },
+            });
+        }
+    }
+    checks.sort_by(|a, b| (a.day, &a.input, a.part).cmp(&(b.day, &b.input, b.part)));
+    Ok(checks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers: Answers = "# comment\n\n07/2/day07-example = 24933642\n5/a/day05 = CMZ\n"
+            .parse()
+            .unwrap();
+        assert_eq!(answers.get(7, Part::Two, "day07-example"), Some("24933642"));
+        assert_eq!(answers.get(5, Part::One, "day05"), Some("CMZ"));
+        assert_eq!(answers.get(5, Part::Two, "day05"), None);
+    }
+
+    #[test]
+    fn test_parse_answers_malformed() {
+        assert!(matches!(
+            "01/1 = 3".parse::<Answers>(),
+            Err(AocError::Malformed { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_unreadable_input_is_an_error_row() {
+        let dir = std::env::temp_dir().join(format!("aoc-verify-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("day06.txt")).unwrap();
+        std::fs::write(dir.join("day06-example.txt"), crate::day06::EXAMPLE).unwrap();
+        let checks = verify(&Answers::default(), &dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        let statuses: Vec<(&str, bool)> = checks.iter().map(|c| (c.input.as_str(), c.failed())).collect();
+        assert_eq!(
+            statuses,
+            vec![("day06", true), ("day06", true), ("day06-example", false), ("day06-example", false)]
+        );
+    }
+
+    #[test]
+    fn test_input_day() {
+        assert_eq!(input_day("day07"), Some(7));
+        assert_eq!(input_day("day07-example"), Some(7));
+        assert_eq!(input_day("day071"), None);
+        assert_eq!(input_day("notes"), None);
+        assert_eq!(input_day("day1é"), None);
+        assert_eq!(input_day("day1"), Some(1));
+    }
+}
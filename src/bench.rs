@@ -0,0 +1,215 @@
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+use crate::day03::{self, Day03};
+use crate::day08::{self, Day08};
+use crate::error::AocError;
+use crate::format::json_string;
+use crate::solution::Solution;
+
+/// Summary of the samples taken for one stage.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Stats {
+        samples.sort();
+        let n = samples.len();
+        let median = match n {
+            0 => Duration::ZERO,
+            _ if n % 2 == 1 => samples[n / 2],
+            _ => (samples[n / 2 - 1] + samples[n / 2]) / 2,
+        };
+        Stats {
+            min: samples.first().copied().unwrap_or_default(),
+            median,
+            max: samples.last().copied().unwrap_or_default(),
+        }
+    }
+}
+
+/// Times `f` over `iterations` runs.
+pub fn measure<T>(
+    iterations: usize,
+    mut f: impl FnMut() -> Result<T, AocError>,
+) -> Result<Stats, AocError> {
+    let mut samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        std::hint::black_box(f()?);
+        samples.push(start.elapsed());
+    }
+    Ok(Stats::from_samples(&mut samples))
+}
+
+#[derive(Debug)]
+pub struct Timings {
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+/// Times the parsing and both parts of a solution separately; the parts are
+/// always run on the same parsed input.
+pub fn bench<S: Solution>(input: &str, iterations: usize) -> Result<Timings, AocError> {
+    let parse = measure(iterations, || S::parse(input))?;
+    let parsed = S::parse(input)?;
+    let part1 = measure(iterations, || S::part1(&parsed))?;
+    let part2 = measure(iterations, || S::part2(&parsed))?;
+    Ok(Timings {
+        parse,
+        part1,
+        part2,
+    })
+}
+
+/// An alternative algorithm for one of the parts, timed on the parsed input.
+pub struct Variant {
+    pub day: u8,
+    pub name: &'static str,
+    pub bench: fn(&str, usize) -> Result<Stats, AocError>,
+}
+
+/// The lines of a valid day 3 input. Both day 3 variants start from them,
+/// so that building the sets is part of what is timed.
+fn rucksack_lines(input: &str) -> Result<Vec<String>, AocError> {
+    Day03::parse(input)?;
+    Ok(input.lines().map(String::from).collect())
+}
+
+pub const VARIANTS: [Variant; 6] = [
+    Variant {
+        day: 3,
+        name: "part 1 (hash set)",
+        bench: |input, iterations| {
+            let rucksacks = rucksack_lines(input)?;
+            measure(iterations, || day03::part1_by_hash_set(&rucksacks))
+        },
+    },
+    Variant {
+        day: 3,
+        name: "part 1 (item set)",
+        bench: |input, iterations| {
+            let rucksacks = rucksack_lines(input)?;
+            measure(iterations, || day03::part1_by_item_set(&rucksacks))
+        },
+    },
+    Variant {
+        day: 3,
+        name: "part 2 (hash set)",
+        bench: |input, iterations| {
+            let rucksacks = rucksack_lines(input)?;
+            measure(iterations, || day03::part2_by_hash_set(&rucksacks))
+        },
+    },
+    Variant {
+        day: 3,
+        name: "part 2 (item set)",
+        bench: |input, iterations| {
+            let rucksacks = rucksack_lines(input)?;
+            measure(iterations, || day03::part2_by_item_set(&rucksacks))
+        },
+    },
+    Variant {
+        day: 8,
+        name: "part 1 (row count)",
+        bench: |input, iterations| {
+            let forest = Day08::parse(input)?;
+            measure(iterations, || Ok(day08::part1_by_row_count(&forest)))
+        },
+    },
+    Variant {
+        day: 8,
+        name: "part 1 (nested map)",
+        bench: |input, iterations| {
+            let forest = Day08::parse(input)?;
+            measure(iterations, || Ok(day08::part1_by_nested_map(&forest)))
+        },
+    },
+];
+
+/// One line of a benchmark report.
+#[derive(Debug)]
+pub struct Row {
+    pub day: u8,
+    pub stage: String,
+    pub iterations: usize,
+    pub stats: Stats,
+}
+
+pub fn rows(day: u8, iterations: usize, timings: &Timings) -> Vec<Row> {
+    [
+        ("parse", timings.parse),
+        ("part 1", timings.part1),
+        ("part 2", timings.part2),
+    ]
+    .into_iter()
+    .map(|(stage, stats)| Row {
+        day,
+        stage: stage.to_string(),
+        iterations,
+        stats,
+    })
+    .collect()
+}
+
+pub fn to_csv(rows: &[Row]) -> String {
+    let mut csv = String::from("day,stage,iterations,min_ns,median_ns,max_ns\n");
+    for row in rows {
+        writeln!(
+            csv,
+            "{},{},{},{},{},{}",
+            row.day,
+            row.stage,
+            row.iterations,
+            row.stats.min.as_nanos(),
+            row.stats.median.as_nanos(),
+            row.stats.max.as_nanos()
+        )
+        .unwrap();
+    }
+    csv
+}
+
+pub fn to_json(rows: &[Row]) -> String {
+    let entries: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            format!(
+                "  {{\"day\": {}, \"stage\": {}, \"iterations\": {}, \"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+                row.day,
+                json_string(&row.stage),
+                row.iterations,
+                row.stats.min.as_nanos(),
+                row.stats.median.as_nanos(),
+                row.stats.max.as_nanos()
+            )
+        })
+        .collect();
+    format!("[\n{}\n]\n", entries.join(",\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let mut samples: Vec<Duration> = [5, 1, 3, 4].into_iter().map(Duration::from_millis).collect();
+        let stats = Stats::from_samples(&mut samples);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(3500));
+        assert_eq!(stats.max, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn test_bench_example() {
+        let timings = bench::<Day08>(day08::EXAMPLE, 3).unwrap();
+        assert!(timings.part1.min <= timings.part1.max);
+        assert_eq!(rows(8, 3, &timings).len(), 3);
+    }
+}
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use advent_of_code_2022::{day02::Day02, runner::{self, Part}};
+
+fn main() -> ExitCode {
+    runner::solve::<Day02>(2, &[Part::Two])
+}
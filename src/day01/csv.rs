@@ -0,0 +1,116 @@
+//! The inventory as CSV, one food per row: `elf,item,calories`. Elves and
+//! items are numbered from 1.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use super::{Elf, Food, Inventory};
+use crate::error::AocError;
+
+pub const HEADER: &str = "elf,item,calories";
+
+pub fn to_csv(inventory: &Inventory) -> String {
+    let mut csv = format!("{}\n", HEADER);
+    for (elf, foods) in inventory.elves.iter().enumerate() {
+        for (item, food) in foods.foods.iter().enumerate() {
+            writeln!(csv, "{},{},{}", elf + 1, item + 1, food).unwrap();
+        }
+    }
+    csv
+}
+
+/// Reads an inventory back. Rows may come in any order, but every elf from
+/// 1 to the highest one must have at least one item and no item may appear
+/// twice.
+pub fn from_csv(csv: &str) -> Result<Inventory, AocError> {
+    let mut lines = csv.lines().enumerate();
+    match lines.next() {
+        Some((_, header)) if header.trim() == HEADER => {}
+        _ => {
+            return Err(AocError::Malformed {
+                line: 1,
+                reason: format!("expected the header `{}`", HEADER),
+            })
+        }
+    }
+
+    let mut foods: BTreeMap<(usize, usize), Food> = BTreeMap::new();
+    for (i, line) in lines {
+        let line_number = i + 1;
+        let line = line.trim_end();
+        if line.is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(',').collect();
+        let [elf, item, calories] = fields[..] else {
+            return Err(AocError::Malformed {
+                line: line_number,
+                reason: format!("expected 3 fields, found {}", fields.len()),
+            });
+        };
+        let column = |field: usize| fields[..field].iter().map(|f| f.len() + 1).sum::<usize>() + 1;
+        let number = |field: usize, s: &str| match s.trim().parse::<usize>() {
+            Ok(0) => Err(AocError::Malformed {
+                line: line_number,
+                reason: "elves and items are numbered from 1".into(),
+            }),
+            Ok(n) => Ok(n),
+            Err(e) => Err(AocError::parse(line_number, column(field), e)),
+        };
+        let key = (number(0, elf)?, number(1, item)?);
+        let food: Food = calories.trim().parse().map_err(|e| AocError::parse(line_number, column(2), e))?;
+        if foods.insert(key, food).is_some() {
+            return Err(AocError::Malformed {
+                line: line_number,
+                reason: format!("item {} of elf {} appears twice", key.1, key.0),
+            });
+        }
+    }
+
+    let mut elves: Vec<Elf> = vec![];
+    for ((elf, _), food) in foods {
+        if elf > elves.len() + 1 {
+            return Err(AocError::Invalid(format!("elf {} has no items", elves.len() + 1)));
+        }
+        if elf > elves.len() {
+            elves.push(Elf { foods: vec![] });
+        }
+        elves[elf - 1].foods.push(food);
+    }
+    Ok(Inventory { elves })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day01::{Day01, EXAMPLE};
+    use crate::solution::Solution;
+
+    #[test]
+    fn test_csv_round_trip() {
+        let inventory = Day01::parse(EXAMPLE).unwrap();
+        let csv = to_csv(&inventory);
+        assert!(csv.starts_with("elf,item,calories\n1,1,1000\n1,2,2000\n"));
+        assert_eq!(from_csv(&csv).unwrap().to_string(), EXAMPLE);
+    }
+
+    #[test]
+    fn test_csv_any_order() {
+        let inventory = from_csv("elf,item,calories\n2,1,5\n1,2,3\n1,1,4\n").unwrap();
+        assert_eq!(inventory.to_string(), "4\n3\n\n5\n");
+    }
+
+    #[test]
+    fn test_csv_errors() {
+        assert!(matches!(from_csv("1,1,1\n"), Err(AocError::Malformed { line: 1, .. })));
+        assert!(matches!(
+            from_csv("elf,item,calories\n1,1,-5\n"),
+            Err(AocError::Parse { line: 2, column: 5, .. })
+        ));
+        assert!(matches!(
+            from_csv("elf,item,calories\n1,1,5\n1,1,6\n"),
+            Err(AocError::Malformed { line: 3, .. })
+        ));
+        assert!(matches!(from_csv("elf,item,calories\n2,1,5\n"), Err(AocError::Invalid(_))));
+    }
+}
@@ -0,0 +1,321 @@
+pub mod csv;
+pub mod rebalance;
+pub mod stats;
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use crate::error::AocError;
+use crate::solution::Solution;
+
+/// The example from the puzzle description.
+pub const EXAMPLE: &str = include_str!("../../input/day01-example.txt");
+
+#[derive(Debug)]
+pub struct Elf {
+    pub foods: Vec<Food>
+}
+
+impl FromStr for Elf {
+    type Err = ParseFoodError;
+
+    fn from_str(elf_spec: &str) -> Result<Self, Self::Err> {
+        let foods: Vec<Food> = elf_spec
+        .lines()
+        .map(|s: &str| s.parse::<Food>())
+        .collect::<Result<Vec<Food>, _>>()?;
+
+        Ok(Elf { foods })
+    }
+}
+
+/// One food per line, each followed by a newline.
+impl fmt::Display for Elf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.foods.iter().try_for_each(|food| writeln!(f, "{}", food))
+    }
+}
+
+/// The calories carried by an elf, or `None` if they do not fit in a `u64`.
+pub fn total_calories(elf: &Elf) -> Option<u64> {
+    elf.foods
+    .iter()
+    .try_fold(0u64, |total, food| total.checked_add(food.calories))
+}
+
+fn overflow(elf: usize) -> String {
+    format!("the calories carried by elf {} overflow", elf + 1)
+}
+
+/// The elves in the order they appear in the input.
+#[derive(Debug)]
+pub struct Inventory {
+    pub elves: Vec<Elf>
+}
+
+/// An elf's position in the inventory and the calories it carries.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Ranked {
+    pub index: usize,
+    pub total: u64,
+}
+
+/// The puzzle input format: elves separated by blank lines.
+impl fmt::Display for Inventory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, elf) in self.elves.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", elf)?;
+        }
+        Ok(())
+    }
+}
+
+impl Inventory {
+    /// The calories carried by each elf, failing on the first elf whose
+    /// total overflows.
+    pub fn totals(&self) -> Result<Vec<u64>, AocError> {
+        self.elves
+        .iter()
+        .enumerate()
+        .map(|(i, elf)| total_calories(elf).ok_or_else(|| AocError::Invalid(overflow(i))))
+        .collect()
+    }
+
+    /// The `k` elves carrying the most calories, heaviest first. There are
+    /// fewer than `k` if the inventory is smaller.
+    pub fn top_k_elves(&self, k: usize) -> Result<Vec<Ranked>, AocError> {
+        Ok(top_k(self.totals()?, k))
+    }
+}
+
+/// The `k` largest totals with their positions, largest first; ties go to
+/// the earlier position. Only `k` totals are kept at any time.
+pub fn top_k(totals: impl IntoIterator<Item = u64>, k: usize) -> Vec<Ranked> {
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    for (index, total) in totals.into_iter().enumerate() {
+        heap.push(Reverse((total, Reverse(index))));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((total, Reverse(index)))| Ranked { index, total })
+        .collect()
+}
+
+/// Ranks the elves read from `reader` without keeping the inventory in memory.
+pub fn top_k_elves_from(reader: impl BufRead, k: usize) -> Result<Vec<Ranked>, AocError> {
+    let mut error = None;
+    let totals = elves(reader).enumerate().map_while(|(i, elf)| {
+        match elf.and_then(|elf| total_calories(&elf).ok_or_else(|| AocError::Invalid(overflow(i)))) {
+            Ok(total) => Some(total),
+            Err(e) => {
+                error = Some(e);
+                None
+            }
+        }
+    });
+    let ranked = top_k(totals, k);
+    error.map_or(Ok(ranked), Err)
+}
+
+/// Reads an inventory one elf at a time. Line endings may be `\n` or
+/// `\r\n`, elves may be separated by any number of blank lines and
+/// surrounding whitespace is ignored. An elf whose calories overflow is
+/// reported at the line where they do.
+pub struct Elves<R> {
+    reader: R,
+    buffer: String,
+    line: usize,
+    elf: usize,
+}
+
+pub fn elves<R: BufRead>(reader: R) -> Elves<R> {
+    Elves { reader, buffer: String::new(), line: 0, elf: 0 }
+}
+
+impl<R: BufRead> Iterator for Elves<R> {
+    type Item = Result<Elf, AocError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut foods = vec![];
+        let mut total = 0u64;
+        loop {
+            self.buffer.clear();
+            match self.reader.read_line(&mut self.buffer) {
+                Ok(0) => break,
+                Ok(_) => self.line += 1,
+                Err(source) => return Some(Err(AocError::Io { path: "inventory".into(), source })),
+            }
+            let food = self.buffer.trim();
+            if food.is_empty() {
+                if foods.is_empty() {
+                    continue;
+                }
+                break;
+            }
+            let column = self.buffer.len() - self.buffer.trim_start().len() + 1;
+            let food = match food.parse::<Food>() {
+                Ok(food) => food,
+                Err(e) => return Some(Err(AocError::parse(self.line, column, e))),
+            };
+            let Some(sum) = total.checked_add(food.calories) else {
+                return Some(Err(AocError::Malformed { line: self.line, reason: overflow(self.elf) }));
+            };
+            total = sum;
+            foods.push(food);
+        }
+        if foods.is_empty() {
+            None
+        } else {
+            self.elf += 1;
+            Some(Ok(Elf { foods }))
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Food {
+    pub calories: u64
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ParseFoodError {
+    #[error("Calories cannot be negative: {0:?}")]
+    Negative(String),
+    #[error("Invalid calories {0:?}: {1}")]
+    Int(String, std::num::ParseIntError),
+}
+
+impl fmt::Display for Food {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.calories)
+    }
+}
+
+impl FromStr for Food {
+    type Err = ParseFoodError;
+    
+    fn from_str(food_spec: &str) -> Result<Self, Self::Err> {
+        if food_spec.starts_with('-') {
+            return Err(ParseFoodError::Negative(food_spec.to_string()));
+        }
+        let calories: u64 = food_spec
+            .parse()
+            .map_err(|e| ParseFoodError::Int(food_spec.to_string(), e))?;
+        
+        Ok(Food { calories })
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Inventory;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Inventory, AocError> {
+        let elves = elves(input.as_bytes()).collect::<Result<Vec<Elf>, _>>()?;
+
+        Ok(Inventory { elves })
+    }
+
+    fn part1(inventory: &Inventory) -> Result<u64, AocError> {
+        inventory
+        .top_k_elves(1)?
+        .first()
+        .map(|elf| elf.total)
+        .ok_or_else(|| AocError::NoAnswer("there are no elves".into()))
+    }
+
+    fn part2(inventory: &Inventory) -> Result<u64, AocError> {
+        let top3 = inventory.top_k_elves(3)?;
+        if top3.len() < 3 {
+            return Err(AocError::NoAnswer("there are fewer than three elves".into()));
+        }
+
+        top3.iter()
+        .try_fold(0u64, |sum, elf| sum.checked_add(elf.total))
+        .ok_or_else(|| AocError::Invalid("the calories carried by the top three elves overflow".into()))
+    }
+}
+
+crate::example_tests!(Day01, example: EXAMPLE => (24000, 45000));
+
+#[test]
+fn test_top_k_elves() {
+    let inventory = Day01::parse(EXAMPLE).unwrap();
+    assert_eq!(
+        inventory.top_k_elves(2).unwrap(),
+        vec![Ranked { index: 3, total: 24000 }, Ranked { index: 2, total: 11000 }]
+    );
+    assert_eq!(inventory.top_k_elves(10).unwrap().len(), 5);
+    assert!(inventory.top_k_elves(0).unwrap().is_empty());
+}
+
+#[test]
+fn test_top_k_ties() {
+    let ranked: Vec<usize> = top_k([5, 7, 5, 7], 3).iter().map(|r| r.index).collect();
+    assert_eq!(ranked, vec![1, 3, 0]);
+}
+
+#[test]
+fn test_elves_tolerate_line_endings() {
+    let totals: Vec<u64> = elves("\r\n1000\r\n2000\r\n\r\n\r\n 3000  \n\n\n".as_bytes())
+        .map(|elf| total_calories(&elf.unwrap()).unwrap())
+        .collect();
+    assert_eq!(totals, vec![3000, 3000]);
+}
+
+#[test]
+fn test_elves_error_position() {
+    let error = elves("1000\n\n\n  12a\n".as_bytes()).nth(1).unwrap().unwrap_err();
+    assert!(matches!(error, AocError::Parse { line: 4, column: 3, .. }));
+}
+
+#[test]
+fn test_top_k_elves_from_reader() {
+    let generated = crate::generate::generate(1, 7, 2000).unwrap();
+    let top3 = top_k_elves_from(generated.input.as_bytes(), 3).unwrap();
+    assert_eq!(top3[0].total.to_string(), generated.part1);
+    assert_eq!(top3.iter().map(|elf| elf.total).sum::<u64>().to_string(), generated.part2);
+}
+
+#[test]
+fn test_negative_calories() {
+    let error = Day01::parse("1000\n\n-20\n").unwrap_err();
+    assert!(matches!(error, AocError::Parse { line: 3, column: 1, .. }));
+    assert!(error.to_string().contains("negative"));
+}
+
+#[test]
+fn test_overflowing_elf() {
+    let input = "1\n\n18446744073709551615\n1\n";
+    let error = Day01::parse(input).unwrap_err();
+    assert!(matches!(error, AocError::Malformed { line: 4, .. }));
+    assert!(error.to_string().contains("elf 2"));
+    assert!(top_k_elves_from(input.as_bytes(), 1).is_err());
+}
+
+#[test]
+fn test_overflowing_top_three() {
+    let max = u64::MAX;
+    let inventory = Day01::parse(&format!("{}\n\n{}\n\n1\n", max, max)).unwrap();
+    assert_eq!(Day01::part1(&inventory).unwrap(), max);
+    assert!(matches!(Day01::part2(&inventory), Err(AocError::Invalid(_))));
+}
+
+#[test]
+fn test_display_round_trip() {
+    assert_eq!(Day01::parse(EXAMPLE).unwrap().to_string(), EXAMPLE);
+    let generated = crate::generate::generate(1, 5, 50).unwrap();
+    assert_eq!(Day01::parse(&generated.input).unwrap().to_string(), generated.input);
+}
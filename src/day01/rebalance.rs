@@ -0,0 +1,418 @@
+//! Redistributes the food between the elves so that the heaviest load is as
+//! light as possible (multiway number partitioning).
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt::Write;
+
+use super::{overflow, Inventory};
+use crate::error::AocError;
+
+/// Largest number of items the exact solver is used for by default.
+pub const EXACT_ITEMS: usize = 16;
+
+/// Largest `items × elves` Karmarkar–Karp is used for by default; above it
+/// the partial partitions take too much memory.
+pub const KARMARKAR_KARP_CELLS: usize = 1_000_000;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Method {
+    /// Branch and bound; optimal but exponential.
+    Exact,
+    /// Largest item first onto the lightest elf.
+    Greedy,
+    /// Largest differencing method.
+    KarmarkarKarp,
+}
+
+impl Method {
+    /// The best method that is still affordable for the given size.
+    pub fn for_size(items: usize, elves: usize) -> Method {
+        if items <= EXACT_ITEMS {
+            Method::Exact
+        } else if items.saturating_mul(elves) <= KARMARKAR_KARP_CELLS {
+            Method::KarmarkarKarp
+        } else {
+            Method::Greedy
+        }
+    }
+}
+
+impl std::str::FromStr for Method {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "exact" => Ok(Method::Exact),
+            "greedy" => Ok(Method::Greedy),
+            "kk" => Ok(Method::KarmarkarKarp),
+            _ => Err(s.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for Method {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Method::Exact => f.pad("exact"),
+            Method::Greedy => f.pad("greedy"),
+            Method::KarmarkarKarp => f.pad("kk"),
+        }
+    }
+}
+
+/// Item `item` of elf `from` is handed to elf `to`. Elves and items are
+/// indexed as in the original inventory.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Transfer {
+    pub from: usize,
+    pub item: usize,
+    pub to: usize,
+    pub calories: u64,
+}
+
+#[derive(Debug)]
+pub struct Plan {
+    pub method: Method,
+    pub transfers: Vec<Transfer>,
+    /// Calories carried by each elf before and after the transfers.
+    pub before: Vec<u64>,
+    pub after: Vec<u64>,
+}
+
+impl Plan {
+    pub fn max_before(&self) -> u64 {
+        self.before.iter().copied().max().unwrap_or(0)
+    }
+
+    pub fn max_after(&self) -> u64 {
+        self.after.iter().copied().max().unwrap_or(0)
+    }
+}
+
+/// The largest load of an assignment of items to bins.
+fn max_load(items: &[u64], assignment: &[usize], bins: usize) -> u64 {
+    let mut loads = vec![0u64; bins];
+    for (&item, &bin) in items.iter().zip(assignment) {
+        loads[bin] = loads[bin].saturating_add(item);
+    }
+    loads.into_iter().max().unwrap_or(0)
+}
+
+/// Indices of `items`, largest item first.
+fn largest_first(items: &[u64]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..items.len()).collect();
+    order.sort_by_key(|&i| Reverse(items[i]));
+    order
+}
+
+fn greedy(items: &[u64], bins: usize) -> Vec<usize> {
+    let mut assignment = vec![0; items.len()];
+    let mut loads: BinaryHeap<Reverse<(u64, usize)>> = (0..bins).map(|bin| Reverse((0, bin))).collect();
+    for i in largest_first(items) {
+        let Reverse((load, bin)) = loads.pop().unwrap();
+        assignment[i] = bin;
+        loads.push(Reverse((load.saturating_add(items[i]), bin)));
+    }
+    assignment
+}
+
+/// A partial partition: the load and items of every bin, heaviest first.
+type Partition = Vec<(u64, Vec<usize>)>;
+
+fn karmarkar_karp(items: &[u64], bins: usize) -> Vec<usize> {
+    let spread = |p: &Partition| p[0].0 - p[p.len() - 1].0;
+    let mut partitions: BinaryHeap<(u64, usize)> = BinaryHeap::new();
+    let mut store: Vec<Option<Partition>> = vec![];
+    for (i, &item) in items.iter().enumerate() {
+        let mut partition: Partition = vec![(0, vec![]); bins];
+        partition[0] = (item, vec![i]);
+        partitions.push((spread(&partition), store.len()));
+        store.push(Some(partition));
+    }
+
+    // Merge the two partitions with the largest spread, pairing the
+    // heaviest bins of one with the lightest of the other.
+    while partitions.len() > 1 {
+        let (_, a) = partitions.pop().unwrap();
+        let (_, b) = partitions.pop().unwrap();
+        let a = store[a].take().unwrap();
+        let b = store[b].take().unwrap();
+        let mut merged: Partition = a
+            .into_iter()
+            .zip(b.into_iter().rev())
+            .map(|((load_a, mut items_a), (load_b, items_b))| {
+                items_a.extend(items_b);
+                (load_a.saturating_add(load_b), items_a)
+            })
+            .collect();
+        merged.sort_by_key(|(load, _)| Reverse(*load));
+        partitions.push((spread(&merged), store.len()));
+        store.push(Some(merged));
+    }
+
+    let mut assignment = vec![0; items.len()];
+    if let Some((_, last)) = partitions.pop() {
+        for (bin, (_, members)) in store[last].take().unwrap().into_iter().enumerate() {
+            for i in members {
+                assignment[i] = bin;
+            }
+        }
+    }
+    assignment
+}
+
+struct Search<'a> {
+    items: &'a [u64],
+    order: Vec<usize>,
+    loads: Vec<u64>,
+    current: Vec<usize>,
+    best: u64,
+    best_assignment: Vec<usize>,
+    lower_bound: u64,
+}
+
+impl Search<'_> {
+    fn place(&mut self, depth: usize, max: u64) {
+        if self.best == self.lower_bound {
+            return;
+        }
+        let Some(&i) = self.order.get(depth) else {
+            self.best = max;
+            self.best_assignment = self.current.clone();
+            return;
+        };
+        for bin in 0..self.loads.len() {
+            // Bins with the same load are interchangeable.
+            if self.loads[..bin].contains(&self.loads[bin]) {
+                continue;
+            }
+            let load = self.loads[bin].saturating_add(self.items[i]);
+            if load >= self.best {
+                continue;
+            }
+            self.loads[bin] = load;
+            self.current[i] = bin;
+            self.place(depth + 1, max.max(load));
+            self.loads[bin] -= self.items[i];
+        }
+    }
+}
+
+/// Branch and bound, pruning anything no better than `known`.
+fn exact(items: &[u64], bins: usize, known: Vec<usize>) -> Vec<usize> {
+    let total: u128 = items.iter().map(|&i| i as u128).sum();
+    let average = total.div_ceil(bins.max(1) as u128).min(u64::MAX as u128) as u64;
+    let mut search = Search {
+        items,
+        order: largest_first(items),
+        loads: vec![0; bins],
+        current: vec![0; items.len()],
+        best: max_load(items, &known, bins),
+        best_assignment: known,
+        lower_bound: average.max(items.iter().copied().max().unwrap_or(0)),
+    };
+    search.place(0, 0);
+    search.best_assignment
+}
+
+/// Matches the bins of a partition with the elves, keeping as many calories
+/// where they already are as possible (greedily).
+fn bins_to_elves(origins: &[usize], items: &[u64], assignment: &[usize], elves: usize) -> Vec<usize> {
+    let mut kept: HashMap<(usize, usize), u64> = HashMap::new();
+    for ((&elf, &item), &bin) in origins.iter().zip(items).zip(assignment) {
+        let calories = kept.entry((bin, elf)).or_default();
+        *calories = calories.saturating_add(item);
+    }
+    let mut pairs: Vec<((usize, usize), u64)> = kept.into_iter().collect();
+    pairs.sort_by_key(|&((bin, elf), calories)| (Reverse(calories), bin, elf));
+
+    let mut elf_of_bin: Vec<Option<usize>> = vec![None; elves];
+    let mut taken = vec![false; elves];
+    for ((bin, elf), _) in pairs {
+        if elf_of_bin[bin].is_none() && !taken[elf] {
+            elf_of_bin[bin] = Some(elf);
+            taken[elf] = true;
+        }
+    }
+    let mut free = (0..elves).filter(|&elf| !taken[elf]);
+    elf_of_bin
+        .into_iter()
+        .map(|elf| elf.unwrap_or_else(|| free.next().unwrap()))
+        .collect()
+}
+
+/// Plans the transfers with `method`, or the method suited to the size of
+/// the inventory. The plan is never worse than leaving the food as it is.
+pub fn plan(inventory: &Inventory, method: Option<Method>) -> Result<Plan, AocError> {
+    let before = inventory.totals()?;
+    let elves = before.len();
+    if elves == 0 {
+        return Err(AocError::NoAnswer("there are no elves".into()));
+    }
+    let (mut origins, mut positions, mut items) = (vec![], vec![], vec![]);
+    for (elf, foods) in inventory.elves.iter().enumerate() {
+        for (position, food) in foods.foods.iter().enumerate() {
+            origins.push(elf);
+            positions.push(position);
+            items.push(food.calories);
+        }
+    }
+    let method = method.unwrap_or_else(|| Method::for_size(items.len(), elves));
+    if method == Method::Exact && items.len() > EXACT_ITEMS {
+        return Err(AocError::Invalid(format!(
+            "the exact method handles at most {} items, not {}; use greedy or kk",
+            EXACT_ITEMS,
+            items.len()
+        )));
+    }
+
+    let mut assignment = match method {
+        Method::Greedy => greedy(&items, elves),
+        Method::KarmarkarKarp => karmarkar_karp(&items, elves),
+        Method::Exact => {
+            let start = greedy(&items, elves);
+            if max_load(&items, &origins, elves) <= max_load(&items, &start, elves) {
+                exact(&items, elves, origins.clone())
+            } else {
+                exact(&items, elves, start)
+            }
+        }
+    };
+    if max_load(&items, &assignment, elves) >= max_load(&items, &origins, elves) {
+        assignment = origins.clone();
+    }
+
+    let elf_of_bin = bins_to_elves(&origins, &items, &assignment, elves);
+    let mut after = vec![0u64; elves];
+    let mut transfers = vec![];
+    for i in 0..items.len() {
+        let to = elf_of_bin[assignment[i]];
+        after[to] = after[to].checked_add(items[i]).ok_or_else(|| AocError::Invalid(overflow(to)))?;
+        if to != origins[i] {
+            transfers.push(Transfer { from: origins[i], item: positions[i], to, calories: items[i] });
+        }
+    }
+    Ok(Plan { method, transfers, before, after })
+}
+
+impl Plan {
+    pub fn to_table(&self) -> String {
+        let mut out = format!(
+            "Method: {}\nHeaviest load: {} -> {}\n\n",
+            self.method,
+            self.max_before(),
+            self.max_after()
+        );
+        writeln!(out, "Transfers: {}", self.transfers.len()).unwrap();
+        for t in &self.transfers {
+            writeln!(
+                out,
+                "  move item {} ({} calories) from elf {} to elf {}",
+                t.item + 1,
+                t.calories,
+                t.from + 1,
+                t.to + 1
+            )
+            .unwrap();
+        }
+        writeln!(out, "\n{:>5}  {:>12}  {:>12}", "Elf", "Before", "After").unwrap();
+        for (elf, (before, after)) in self.before.iter().zip(&self.after).enumerate() {
+            writeln!(out, "{:>5}  {:>12}  {:>12}", elf + 1, before, after).unwrap();
+        }
+        out
+    }
+
+    pub fn to_json(&self) -> String {
+        let transfers: Vec<String> = self
+            .transfers
+            .iter()
+            .map(|t| {
+                format!(
+                    "{{\"from\": {}, \"item\": {}, \"to\": {}, \"calories\": {}}}",
+                    t.from + 1,
+                    t.item + 1,
+                    t.to + 1,
+                    t.calories
+                )
+            })
+            .collect();
+        let list = |totals: &[u64]| totals.iter().map(u64::to_string).collect::<Vec<_>>().join(", ");
+        format!(
+            "{{\"method\": \"{}\", \"max_before\": {}, \"max_after\": {}, \"transfers\": [{}], \"before\": [{}], \"after\": [{}]}}\n",
+            self.method,
+            self.max_before(),
+            self.max_after(),
+            transfers.join(", "),
+            list(&self.before),
+            list(&self.after)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day01::{Day01, EXAMPLE};
+    use crate::solution::Solution;
+
+    /// Checks that the transfers turn the totals before into the totals after.
+    fn check(inventory: &Inventory, plan: &Plan) {
+        let mut totals = plan.before.clone();
+        for t in &plan.transfers {
+            assert_eq!(inventory.elves[t.from].foods[t.item].calories, t.calories);
+            totals[t.from] -= t.calories;
+            totals[t.to] += t.calories;
+        }
+        assert_eq!(totals, plan.after);
+    }
+
+    #[test]
+    fn test_exact_example() {
+        let inventory = Day01::parse(EXAMPLE).unwrap();
+        let plan = plan(&inventory, None).unwrap();
+        assert_eq!(plan.method, Method::Exact);
+        assert_eq!(plan.max_before(), 24000);
+        assert_eq!(plan.max_after(), 11000);
+        check(&inventory, &plan);
+    }
+
+    #[test]
+    fn test_formats_number_from_one() {
+        let inventory = Day01::parse(EXAMPLE).unwrap();
+        let plan = plan(&inventory, None).unwrap();
+        // Both formats number the elves and their items from 1.
+        assert!(plan.to_table().contains("move item 1 (1000 calories) from elf 1 to elf 5"));
+        assert!(plan.to_json().contains("{\"from\": 1, \"item\": 1, \"to\": 5, \"calories\": 1000}"));
+    }
+
+    #[test]
+    fn test_exact_beats_karmarkar_karp() {
+        let inventory = Day01::parse("8\n7\n6\n5\n4\n\n0\n").unwrap();
+        assert_eq!(plan(&inventory, Some(Method::KarmarkarKarp)).unwrap().max_after(), 16);
+        assert_eq!(plan(&inventory, Some(Method::Exact)).unwrap().max_after(), 15);
+    }
+
+    #[test]
+    fn test_heuristics_on_generated_input() {
+        let generated = crate::generate::generate(1, 3, 200).unwrap();
+        let inventory = Day01::parse(&generated.input).unwrap();
+        for method in [Method::Greedy, Method::KarmarkarKarp] {
+            let plan = plan(&inventory, Some(method)).unwrap();
+            check(&inventory, &plan);
+            assert!(plan.max_after() < plan.max_before());
+        }
+    }
+
+    #[test]
+    fn test_exact_is_limited_in_size() {
+        let generated = crate::generate::generate(1, 3, 30).unwrap();
+        let inventory = Day01::parse(&generated.input).unwrap();
+        assert!(matches!(plan(&inventory, Some(Method::Exact)), Err(AocError::Invalid(_))));
+    }
+
+    #[test]
+    fn test_balanced_inventory_needs_no_transfers() {
+        let inventory = Day01::parse("5\n\n5\n").unwrap();
+        assert!(plan(&inventory, None).unwrap().transfers.is_empty());
+    }
+}
@@ -0,0 +1,244 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use super::Inventory;
+use crate::error::AocError;
+
+/// Percentiles reported besides the median, by the nearest-rank method.
+pub const PERCENTILES: [u8; 5] = [10, 25, 75, 90, 99];
+
+/// Number of buckets in the histograms.
+pub const BUCKETS: usize = 10;
+
+#[derive(Debug, PartialEq)]
+pub struct Summary {
+    pub count: usize,
+    pub min: u64,
+    pub max: u64,
+    pub mean: f64,
+    pub median: f64,
+    pub percentiles: Vec<(u8, u64)>,
+}
+
+impl Summary {
+    /// Summarizes a non-empty list of values.
+    pub fn of(mut values: Vec<u64>) -> Summary {
+        values.sort_unstable();
+        let n = values.len();
+        let median = if n % 2 == 1 {
+            values[n / 2] as f64
+        } else {
+            (values[n / 2 - 1] as f64 + values[n / 2] as f64) / 2.0
+        };
+        let percentiles = PERCENTILES
+            .iter()
+            .map(|&p| {
+                let rank = (p as usize * n).div_ceil(100).max(1);
+                (p, values[rank - 1])
+            })
+            .collect();
+        Summary {
+            count: n,
+            min: values[0],
+            max: values[n - 1],
+            mean: values.iter().map(|&v| v as u128).sum::<u128>() as f64 / n as f64,
+            median,
+            percentiles,
+        }
+    }
+}
+
+/// Values in `lower..=upper`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Bucket {
+    pub lower: u64,
+    pub upper: u64,
+    pub count: usize,
+}
+
+/// Splits the range of `values` in equally wide buckets.
+pub fn histogram(values: &[u64], buckets: usize) -> Vec<Bucket> {
+    let (Some(&min), Some(&max)) = (values.iter().min(), values.iter().max()) else {
+        return vec![];
+    };
+    let width = (max - min) / buckets as u64 + 1;
+    let mut histogram: Vec<Bucket> = (0..=(max - min) / width)
+        .map(|i| min + i * width)
+        .map(|lower| Bucket { lower, upper: lower.saturating_add(width - 1), count: 0 })
+        .collect();
+    for value in values {
+        histogram[((value - min) / width) as usize].count += 1;
+    }
+    histogram
+}
+
+#[derive(Debug)]
+pub struct InventoryStats {
+    /// Of the total calories carried by each elf.
+    pub totals: Summary,
+    /// Of the number of items carried by each elf.
+    pub items: Summary,
+    /// Of the calories of every single food item.
+    pub foods: Summary,
+    pub totals_histogram: Vec<Bucket>,
+    pub foods_histogram: Vec<Bucket>,
+    /// Totals carried by more than one elf, with the indices of those elves.
+    pub duplicates: Vec<(u64, Vec<usize>)>,
+}
+
+pub fn stats(inventory: &Inventory) -> Result<InventoryStats, AocError> {
+    let totals = inventory.totals()?;
+    let foods: Vec<u64> = inventory
+        .elves
+        .iter()
+        .flat_map(|elf| elf.foods.iter().map(|food| food.calories))
+        .collect();
+    if totals.is_empty() {
+        return Err(AocError::NoAnswer("there are no elves".into()));
+    }
+
+    let mut by_total: BTreeMap<u64, Vec<usize>> = BTreeMap::new();
+    for (index, &total) in totals.iter().enumerate() {
+        by_total.entry(total).or_default().push(index);
+    }
+
+    Ok(InventoryStats {
+        totals_histogram: histogram(&totals, BUCKETS),
+        foods_histogram: histogram(&foods, BUCKETS),
+        totals: Summary::of(totals),
+        items: Summary::of(inventory.elves.iter().map(|elf| elf.foods.len() as u64).collect()),
+        foods: Summary::of(foods),
+        duplicates: by_total.into_iter().filter(|(_, elves)| elves.len() > 1).collect(),
+    })
+}
+
+impl InventoryStats {
+    pub fn to_table(&self) -> String {
+        let mut out = format!("Elves: {}\n\n", self.totals.count);
+        write!(out, "{:<14}  {:>6}  {:>8}  {:>8}  {:>10}  {:>10}", "", "Count", "Min", "Max", "Mean", "Median").unwrap();
+        for (p, _) in &self.totals.percentiles {
+            write!(out, "  {:>8}", format!("p{}", p)).unwrap();
+        }
+        out.push('\n');
+        for (name, summary) in [("Totals", &self.totals), ("Items per elf", &self.items), ("Food calories", &self.foods)] {
+            write!(
+                out,
+                "{:<14}  {:>6}  {:>8}  {:>8}  {:>10.1}  {:>10.1}",
+                name, summary.count, summary.min, summary.max, summary.mean, summary.median
+            )
+            .unwrap();
+            for (_, value) in &summary.percentiles {
+                write!(out, "  {:>8}", value).unwrap();
+            }
+            out.push('\n');
+        }
+
+        for (name, histogram) in [("Totals", &self.totals_histogram), ("Food calories", &self.foods_histogram)] {
+            let most = histogram.iter().map(|b| b.count).max().unwrap_or(0).max(1);
+            writeln!(out, "\n{}", name).unwrap();
+            for bucket in histogram {
+                writeln!(
+                    out,
+                    "{:>8} - {:<8} {:>6}  {}",
+                    bucket.lower,
+                    bucket.upper,
+                    bucket.count,
+                    "#".repeat((bucket.count * 40).div_ceil(most))
+                )
+                .unwrap();
+            }
+        }
+
+        writeln!(out, "\nDuplicate totals").unwrap();
+        if self.duplicates.is_empty() {
+            writeln!(out, "none").unwrap();
+        }
+        for (total, elves) in &self.duplicates {
+            let elves: Vec<String> = elves.iter().map(|i| (i + 1).to_string()).collect();
+            writeln!(out, "{:>8}: elves {}", total, elves.join(", ")).unwrap();
+        }
+        out
+    }
+
+    pub fn to_json(&self) -> String {
+        let summary = |s: &Summary| {
+            let percentiles: Vec<String> = s.percentiles.iter().map(|(p, v)| format!("\"p{}\": {}", p, v)).collect();
+            format!(
+                "{{\"count\": {}, \"min\": {}, \"max\": {}, \"mean\": {}, \"median\": {}, {}}}",
+                s.count,
+                s.min,
+                s.max,
+                s.mean,
+                s.median,
+                percentiles.join(", ")
+            )
+        };
+        let histogram = |h: &[Bucket]| {
+            let buckets: Vec<String> = h
+                .iter()
+                .map(|b| format!("{{\"lower\": {}, \"upper\": {}, \"count\": {}}}", b.lower, b.upper, b.count))
+                .collect();
+            format!("[{}]", buckets.join(", "))
+        };
+        let duplicates: Vec<String> = self
+            .duplicates
+            .iter()
+            .map(|(total, elves)| {
+                let elves: Vec<String> = elves.iter().map(|i| (i + 1).to_string()).collect();
+                format!("{{\"total\": {}, \"elves\": [{}]}}", total, elves.join(", "))
+            })
+            .collect();
+        format!(
+            "{{\"elves\": {}, \"totals\": {}, \"items\": {}, \"foods\": {}, \"totals_histogram\": {}, \"foods_histogram\": {}, \"duplicates\": [{}]}}\n",
+            self.totals.count,
+            summary(&self.totals),
+            summary(&self.items),
+            summary(&self.foods),
+            histogram(&self.totals_histogram),
+            histogram(&self.foods_histogram),
+            duplicates.join(", ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day01::{Day01, EXAMPLE};
+    use crate::solution::Solution;
+
+    #[test]
+    fn test_example_stats() {
+        let stats = stats(&Day01::parse(EXAMPLE).unwrap()).unwrap();
+        assert_eq!(stats.totals.mean, 11000.0);
+        assert_eq!(stats.totals.median, 10000.0);
+        assert_eq!(stats.totals.percentiles[0], (10, 4000));
+        assert_eq!(stats.totals.percentiles[4], (99, 24000));
+        assert_eq!((stats.items.min, stats.items.max), (1, 3));
+        assert_eq!(stats.foods.count, 10);
+        assert!(stats.duplicates.is_empty());
+    }
+
+    #[test]
+    fn test_histogram() {
+        let histogram = histogram(&[1, 2, 2, 9, 10], 3);
+        assert_eq!(
+            histogram,
+            vec![
+                Bucket { lower: 1, upper: 4, count: 3 },
+                Bucket { lower: 5, upper: 8, count: 0 },
+                Bucket { lower: 9, upper: 12, count: 2 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let inventory = Day01::parse("1\n2\n\n3\n\n4\n").unwrap();
+        let stats = stats(&inventory).unwrap();
+        assert_eq!(stats.duplicates, vec![(3, vec![0, 1])]);
+        // Both formats number the elves from 1.
+        assert!(stats.to_table().contains("3: elves 1, 2"));
+        assert!(stats.to_json().contains("\"duplicates\": [{\"total\": 3, \"elves\": [1, 2]}]"));
+    }
+}
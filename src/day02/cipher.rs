@@ -0,0 +1,161 @@
+//! Tries every meaning the second column of the guide could have.
+
+use std::fmt;
+use std::fmt::Write;
+
+use super::moves::Move;
+use super::outcome::Outcome;
+use super::round::{OutcomeRound, PlayerRound};
+use super::strategy::{OutcomeStrategy, PlayerStrategy};
+
+/// What `X`, `Y` and `Z` stand for.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Mapping {
+    Moves([Move; 3]),
+    Outcomes([Outcome; 3]),
+}
+
+impl fmt::Display for Mapping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let meanings: Vec<String> = match self {
+            Mapping::Moves(moves) => moves.iter().map(|m| format!("{:?}", m)).collect(),
+            Mapping::Outcomes(outcomes) => outcomes.iter().map(|o| format!("{:?}", o)).collect(),
+        };
+        write!(f, "X={} Y={} Z={}", meanings[0], meanings[1], meanings[2])
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Evaluation {
+    pub mapping: Mapping,
+    pub score: i32,
+}
+
+/// The 6 move and the 6 outcome mappings, with their scores.
+#[derive(Debug)]
+pub struct Analysis {
+    pub evaluations: Vec<Evaluation>,
+}
+
+fn permutations<T: Copy>([a, b, c]: [T; 3]) -> [[T; 3]; 6] {
+    [[a, b, c], [a, c, b], [b, a, c], [b, c, a], [c, a, b], [c, b, a]]
+}
+
+/// Position of a move in the guide when `X`, `Y` and `Z` are rock, paper
+/// and scissors, as [`PlayerRound`] parses them.
+fn letter_of_move(m: Move) -> usize {
+    Move::ALL.iter().position(|&other| other == m).unwrap()
+}
+
+fn letter_of_outcome(o: Outcome) -> usize {
+    Outcome::ALL.iter().position(|&other| other == o).unwrap()
+}
+
+/// Scores the guide under every mapping. `moves` and `outcomes` are the same
+/// guide parsed in both modes.
+pub fn analyse(moves: &PlayerStrategy, outcomes: &OutcomeStrategy) -> Analysis {
+    let mut evaluations = vec![];
+    for mapping in permutations(Move::ALL) {
+        let strategy = PlayerStrategy {
+            rounds: moves
+                .rounds
+                .iter()
+                .map(|r| PlayerRound { opponent: r.opponent, player: mapping[letter_of_move(r.player)] })
+                .collect(),
+        };
+        evaluations.push(Evaluation { mapping: Mapping::Moves(mapping), score: strategy.score() });
+    }
+    for mapping in permutations(Outcome::ALL) {
+        let strategy = OutcomeStrategy {
+            rounds: outcomes
+                .rounds
+                .iter()
+                .map(|r| OutcomeRound { opponent: r.opponent, outcome: mapping[letter_of_outcome(r.outcome)] })
+                .collect(),
+        };
+        evaluations.push(Evaluation { mapping: Mapping::Outcomes(mapping), score: strategy.score() });
+    }
+    Analysis { evaluations }
+}
+
+impl Analysis {
+    /// The highest scoring mapping; the first one on ties.
+    pub fn best(&self) -> Evaluation {
+        self.evaluations
+            .iter()
+            .fold(self.evaluations[0], |best, e| if e.score > best.score { *e } else { best })
+    }
+
+    /// The lowest scoring mapping; the first one on ties.
+    pub fn worst(&self) -> Evaluation {
+        self.evaluations
+            .iter()
+            .fold(self.evaluations[0], |worst, e| if e.score < worst.score { *e } else { worst })
+    }
+
+    pub fn to_table(&self) -> String {
+        let (best, worst) = (self.best(), self.worst());
+        let mut out = format!("{:<8}  {:<36}  {:>8}\n", "Mode", "Mapping", "Score");
+        for e in &self.evaluations {
+            let mode = match e.mapping {
+                Mapping::Moves(_) => "move",
+                Mapping::Outcomes(_) => "outcome",
+            };
+            let mark = if *e == best {
+                "  max"
+            } else if *e == worst {
+                "  min"
+            } else {
+                ""
+            };
+            writeln!(out, "{:<8}  {:<36}  {:>8}{}", mode, e.mapping.to_string(), e.score, mark).unwrap();
+        }
+        out
+    }
+
+    pub fn to_json(&self) -> String {
+        let entry = |e: &Evaluation| {
+            let (mode, meanings): (&str, Vec<String>) = match e.mapping {
+                Mapping::Moves(moves) => ("move", moves.iter().map(|m| format!("\"{:?}\"", m)).collect()),
+                Mapping::Outcomes(outcomes) => ("outcome", outcomes.iter().map(|o| format!("\"{:?}\"", o)).collect()),
+            };
+            format!(
+                "{{\"mode\": \"{}\", \"x\": {}, \"y\": {}, \"z\": {}, \"score\": {}}}",
+                mode, meanings[0], meanings[1], meanings[2], e.score
+            )
+        };
+        let evaluations: Vec<String> = self.evaluations.iter().map(entry).collect();
+        format!(
+            "{{\"evaluations\": [{}], \"max\": {}, \"min\": {}}}\n",
+            evaluations.join(", "),
+            entry(&self.best()),
+            entry(&self.worst())
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day02::{Day02, EXAMPLE};
+
+    #[test]
+    fn test_example_analysis() {
+        let (moves, outcomes) = Day02::strategies(EXAMPLE).unwrap();
+        let analysis = analyse(&moves, &outcomes);
+        assert_eq!(analysis.evaluations.len(), 12);
+        // The puzzle's own readings of the guide.
+        assert_eq!(analysis.evaluations[0].score, 15);
+        assert_eq!(analysis.evaluations[6].score, 12);
+        assert_eq!(
+            analysis.best(),
+            Evaluation { mapping: Mapping::Moves([Move::Scissors, Move::Paper, Move::Rock]), score: 24 }
+        );
+        assert_eq!(
+            analysis.worst(),
+            Evaluation { mapping: Mapping::Moves([Move::Rock, Move::Scissors, Move::Paper]), score: 6 }
+        );
+        let outcome_scores: Vec<i32> = analysis.evaluations[6..].iter().map(|e| e.score).collect();
+        assert_eq!(outcome_scores, vec![12, 15, 15, 15, 18, 15]);
+    }
+}
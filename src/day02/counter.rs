@@ -0,0 +1,181 @@
+//! The best way to play against a known sequence of opponent moves.
+
+use super::moves::Move;
+use super::outcome::Outcome;
+use super::round::OutcomeRound;
+use super::strategy::PlayerStrategy;
+use crate::error::AocError;
+
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+pub struct Constraints {
+    /// Win at most this many rounds.
+    pub max_wins: Option<usize>,
+    /// Draw exactly this many rounds.
+    pub draws: Option<usize>,
+    /// Fail unless the best score reaches this.
+    pub min_score: Option<i32>,
+}
+
+fn score(opponent: Move, outcome: Outcome) -> i32 {
+    OutcomeRound { opponent, outcome }.score()
+}
+
+/// How many rounds against each opponent move are won and drawn.
+type Split = [(usize, usize); 3];
+
+/// Fills `draws` draws into the rounds left after the wins, taking those
+/// that gain the most over a loss first.
+fn place_draws(counts: [usize; 3], wins: [usize; 3], mut draws: usize) -> Option<[usize; 3]> {
+    let mut kinds = [0, 1, 2];
+    kinds.sort_by_key(|&k| {
+        let m = Move::ALL[k];
+        std::cmp::Reverse(score(m, Outcome::Draw) - score(m, Outcome::Loss))
+    });
+    let mut placed = [0; 3];
+    for k in kinds {
+        placed[k] = draws.min(counts[k] - wins[k]);
+        draws -= placed[k];
+    }
+    (draws == 0).then_some(placed)
+}
+
+/// Maximises the total score against `opponents` under `constraints`.
+///
+/// Every round scores more as a win than as a draw and more as a draw than
+/// as a loss, so the best plan wins as many rounds as allowed and draws as
+/// many as allowed of the rest. Rounds against the same move are
+/// interchangeable, so only how many of each kind are won has to be
+/// searched, and the draws then go where they gain the most.
+pub fn solve(opponents: &[Move], constraints: Constraints) -> Result<PlayerStrategy, AocError> {
+    let n = opponents.len();
+    let kind = |m: Move| Move::ALL.iter().position(|&other| other == m).unwrap();
+    let mut counts = [0; 3];
+    for &m in opponents {
+        counts[kind(m)] += 1;
+    }
+    if let Some(draws) = constraints.draws.filter(|&d| d > n) {
+        return Err(AocError::NoAnswer(format!("cannot draw {} of {} rounds", draws, n)));
+    }
+    let wins = constraints
+        .max_wins
+        .unwrap_or(n)
+        .min(n - constraints.draws.unwrap_or(0));
+    let draws = constraints.draws.unwrap_or(n - wins);
+
+    let mut best: Option<(i32, Split)> = None;
+    for rock in 0..=counts[0].min(wins) {
+        for paper in 0..=counts[1].min(wins - rock) {
+            let scissors = wins - rock - paper;
+            if scissors > counts[2] {
+                continue;
+            }
+            let won = [rock, paper, scissors];
+            let Some(drawn) = place_draws(counts, won, draws) else {
+                continue;
+            };
+            let total: i32 = (0..3)
+                .map(|k| {
+                    let m = Move::ALL[k];
+                    let lost = counts[k] - won[k] - drawn[k];
+                    won[k] as i32 * score(m, Outcome::Win)
+                        + drawn[k] as i32 * score(m, Outcome::Draw)
+                        + lost as i32 * score(m, Outcome::Loss)
+                })
+                .sum();
+            if best.is_none_or(|(b, _)| total > b) {
+                best = Some((total, [(won[0], drawn[0]), (won[1], drawn[1]), (won[2], drawn[2])]));
+            }
+        }
+    }
+    let Some((total, mut split)) = best else {
+        return Err(AocError::NoAnswer("no plan meets the constraints".into()));
+    };
+    if let Some(target) = constraints.min_score.filter(|&t| total < t) {
+        return Err(AocError::NoAnswer(format!(
+            "the best score under these constraints is {}, below {}",
+            total, target
+        )));
+    }
+
+    let rounds = opponents
+        .iter()
+        .map(|&opponent| {
+            let (won, drawn) = &mut split[kind(opponent)];
+            let outcome = if *won > 0 {
+                *won -= 1;
+                Outcome::Win
+            } else if *drawn > 0 {
+                *drawn -= 1;
+                Outcome::Draw
+            } else {
+                Outcome::Loss
+            };
+            OutcomeRound { opponent, outcome }.into()
+        })
+        .collect();
+    Ok(PlayerStrategy { rounds })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::Rng;
+
+    const EXAMPLE: [Move; 3] = [Move::Rock, Move::Paper, Move::Scissors];
+
+    #[test]
+    fn test_unconstrained_wins_everything() {
+        let strategy = solve(&EXAMPLE, Constraints::default()).unwrap();
+        assert_eq!(strategy.score(), 8 + 9 + 7);
+        assert_eq!(strategy.rounds[0].player, Move::Paper);
+    }
+
+    #[test]
+    fn test_constraints() {
+        let one_win = Constraints { max_wins: Some(1), ..Constraints::default() };
+        assert_eq!(solve(&EXAMPLE, one_win).unwrap().score(), 19);
+        let lose_all = Constraints { max_wins: Some(0), draws: Some(0), ..Constraints::default() };
+        assert_eq!(solve(&EXAMPLE, lose_all).unwrap().score(), 3 + 1 + 2);
+        let unreachable = Constraints { min_score: Some(30), ..Constraints::default() };
+        assert!(matches!(solve(&EXAMPLE, unreachable), Err(AocError::NoAnswer(_))));
+        let too_many_draws = Constraints { draws: Some(4), ..Constraints::default() };
+        assert!(solve(&EXAMPLE, too_many_draws).is_err());
+    }
+
+    /// The best score by trying every combination of outcomes.
+    fn brute_force(opponents: &[Move], max_wins: usize, draws: usize) -> Option<i32> {
+        let n = opponents.len();
+        (0..3usize.pow(n as u32))
+            .filter_map(|mut code| {
+                let outcomes: Vec<Outcome> = (0..n)
+                    .map(|_| {
+                        let o = Outcome::ALL[code % 3];
+                        code /= 3;
+                        o
+                    })
+                    .collect();
+                let wins = outcomes.iter().filter(|&&o| o == Outcome::Win).count();
+                let drawn = outcomes.iter().filter(|&&o| o == Outcome::Draw).count();
+                (wins <= max_wins && drawn == draws)
+                    .then(|| opponents.iter().zip(&outcomes).map(|(&m, &o)| score(m, o)).sum())
+            })
+            .max()
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        let mut rng = Rng::new(22);
+        for _ in 0..40 {
+            let opponents: Vec<Move> = (0..rng.range(1, 6)).map(|_| rng.choose(&Move::ALL)).collect();
+            let (max_wins, draws) = (rng.range(0, 6), rng.range(0, 6));
+            let constraints = Constraints { max_wins: Some(max_wins), draws: Some(draws), min_score: None };
+            let solved = solve(&opponents, constraints).ok().map(|s| {
+                let wins = s.rounds.iter().filter(|r| r.outcome() == Outcome::Win).count();
+                let drawn = s.rounds.iter().filter(|r| r.outcome() == Outcome::Draw).count();
+                assert!(wins <= max_wins && drawn == draws);
+                s.score()
+            });
+            assert_eq!(solved, brute_force(&opponents, max_wins, draws), "{:?}", opponents);
+        }
+    }
+}
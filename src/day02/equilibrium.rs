@@ -0,0 +1,273 @@
+//! Optimal mixed strategies for the scored game, where the opponent tries
+//! to keep the player's score as low as possible.
+
+use std::fmt::Write;
+
+use super::game::Game;
+use super::moves::Move;
+use super::round::ParseMode;
+
+const EPSILON: f64 = 1e-9;
+
+/// The player's score for each of its moves (rows) against each of the
+/// opponent's (columns).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Payoffs {
+    pub moves: Vec<String>,
+    pub scores: Vec<Vec<f64>>,
+}
+
+impl Payoffs {
+    /// The puzzle's scores, with the moves in the order of [`Move::ALL`].
+    pub fn puzzle() -> Payoffs {
+        Payoffs {
+            moves: Move::ALL.iter().map(|m| format!("{:?}", m)).collect(),
+            scores: Move::ALL
+                .iter()
+                .map(|p| Move::ALL.iter().map(|&o| (p.player_score() + p.against(o).score()) as f64).collect())
+                .collect(),
+        }
+    }
+
+    pub fn of_game(game: &Game) -> Payoffs {
+        let n = game.moves.len();
+        Payoffs {
+            moves: game.moves.clone(),
+            scores: (0..n).map(|p| (0..n).map(|o| game.score(p, o) as f64).collect()).collect(),
+        }
+    }
+
+    /// The expected score of playing `player` against each opponent move.
+    fn against_each(&self, player: &[f64]) -> Vec<f64> {
+        (0..self.moves.len())
+            .map(|o| player.iter().zip(&self.scores).map(|(p, row)| p * row[o]).sum())
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Equilibrium {
+    /// How often each side plays each move.
+    pub player: Vec<f64>,
+    pub opponent: Vec<f64>,
+    /// The expected score per round when both sides play their mix.
+    pub value: f64,
+}
+
+/// Solves the game as a linear program with the simplex method.
+///
+/// With every score shifted to be positive, the opponent's mix is `y / Σy`
+/// for the `y ≥ 0` maximising `Σy` subject to `scores · y ≤ 1`, and the
+/// value is `1 / Σy`. The player's mix is read from the dual of the same
+/// tableau. Bland's rule keeps degenerate pivots from cycling.
+pub fn solve(payoffs: &Payoffs) -> Equilibrium {
+    let n = payoffs.moves.len();
+    let lowest = payoffs.scores.iter().flatten().copied().fold(f64::INFINITY, f64::min);
+    let shift = 1.0 - lowest;
+
+    // Rows are the player's moves, then the objective. Columns are the
+    // opponent's moves, one slack per row, and the right hand side.
+    let width = 2 * n + 1;
+    let mut tableau: Vec<Vec<f64>> = (0..n)
+        .map(|i| {
+            let mut row = vec![0.0; width];
+            for (cell, score) in row.iter_mut().zip(&payoffs.scores[i]) {
+                *cell = score + shift;
+            }
+            row[n + i] = 1.0;
+            row[2 * n] = 1.0;
+            row
+        })
+        .collect();
+    let mut objective = vec![0.0; width];
+    objective[..n].fill(-1.0);
+    tableau.push(objective);
+    let mut basis: Vec<usize> = (n..2 * n).collect();
+
+    while let Some(entering) = (0..2 * n).find(|&j| tableau[n][j] < -EPSILON) {
+        let leaving = (0..n)
+            .filter(|&i| tableau[i][entering] > EPSILON)
+            .min_by(|&a, &b| {
+                let ratio = |i: usize| tableau[i][2 * n] / tableau[i][entering];
+                ratio(a).total_cmp(&ratio(b)).then(basis[a].cmp(&basis[b]))
+            })
+            .expect("the program is bounded");
+        let pivot = tableau[leaving][entering];
+        tableau[leaving].iter_mut().for_each(|x| *x /= pivot);
+        let pivot_row = tableau[leaving].clone();
+        for (i, row) in tableau.iter_mut().enumerate() {
+            let factor = row[entering];
+            if i != leaving && factor != 0.0 {
+                row.iter_mut().zip(&pivot_row).for_each(|(x, p)| *x -= factor * p);
+            }
+        }
+        basis[leaving] = entering;
+    }
+
+    let total = tableau[n][2 * n];
+    let mut opponent = vec![0.0; n];
+    for (i, &j) in basis.iter().enumerate() {
+        if j < n {
+            opponent[j] = tableau[i][2 * n] / total;
+        }
+    }
+    let player = (0..n).map(|i| tableau[n][n + i] / total).collect();
+    Equilibrium { player, opponent, value: 1.0 / total - shift }
+}
+
+/// How a guide fares against the equilibrium.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub mode: ParseMode,
+    pub rounds: usize,
+    /// The score the guide actually got, per round.
+    pub average: f64,
+    /// How often the guide plays each move.
+    pub frequencies: Vec<f64>,
+    /// The expected score per round of playing those frequencies at random
+    /// against the opponent's best reply to them.
+    pub guaranteed: f64,
+}
+
+/// Compares a guide, given as `(opponent, player)` moves indexing
+/// `payoffs`, with the value of the game.
+pub fn compare(payoffs: &Payoffs, mode: ParseMode, rounds: &[(usize, usize)]) -> Comparison {
+    let n = payoffs.moves.len();
+    let total: f64 = rounds.iter().map(|&(o, p)| payoffs.scores[p][o]).sum();
+    let mut counts = vec![0; n];
+    for &(_, p) in rounds {
+        counts[p] += 1;
+    }
+    let frequencies: Vec<f64> = counts.iter().map(|&c| c as f64 / rounds.len() as f64).collect();
+    let guaranteed = if rounds.is_empty() {
+        0.0
+    } else {
+        payoffs.against_each(&frequencies).into_iter().fold(f64::INFINITY, f64::min)
+    };
+    Comparison { mode, rounds: rounds.len(), average: total / rounds.len().max(1) as f64, frequencies, guaranteed }
+}
+
+pub fn to_table(payoffs: &Payoffs, equilibrium: &Equilibrium, comparisons: &[Comparison]) -> String {
+    let width = payoffs.moves.iter().map(|m| m.len()).max().unwrap_or(0).max(4);
+    let mut out = format!("{:<width$}  {:>8}  {:>8}\n", "Move", "Player", "Opponent");
+    for (i, m) in payoffs.moves.iter().enumerate() {
+        writeln!(out, "{:<width$}  {:>8.4}  {:>8.4}", m, equilibrium.player[i], equilibrium.opponent[i]).unwrap();
+    }
+    writeln!(out, "\nValue: {:.4} per round\n", equilibrium.value).unwrap();
+    writeln!(out, "{:<8}  {:>6}  {:>8}  {:>10}  {:>10}", "Guide", "Rounds", "Average", "Guaranteed", "vs value").unwrap();
+    for c in comparisons {
+        writeln!(
+            out,
+            "{:<8}  {:>6}  {:>8.4}  {:>10.4}  {:>+10.4}",
+            mode_name(c.mode),
+            c.rounds,
+            c.average,
+            c.guaranteed,
+            c.average - equilibrium.value
+        )
+        .unwrap();
+    }
+    out
+}
+
+pub fn to_json(payoffs: &Payoffs, equilibrium: &Equilibrium, comparisons: &[Comparison]) -> String {
+    let mix = |weights: &[f64]| {
+        let entries: Vec<String> = payoffs
+            .moves
+            .iter()
+            .zip(weights)
+            .map(|(m, w)| format!("\"{}\": {}", m, w))
+            .collect();
+        format!("{{{}}}", entries.join(", "))
+    };
+    let guides: Vec<String> = comparisons
+        .iter()
+        .map(|c| {
+            format!(
+                "{{\"mode\": \"{}\", \"rounds\": {}, \"average\": {}, \"guaranteed\": {}, \"frequencies\": {}}}",
+                mode_name(c.mode),
+                c.rounds,
+                c.average,
+                c.guaranteed,
+                mix(&c.frequencies)
+            )
+        })
+        .collect();
+    format!(
+        "{{\"player\": {}, \"opponent\": {}, \"value\": {}, \"guides\": [{}]}}\n",
+        mix(&equilibrium.player),
+        mix(&equilibrium.opponent),
+        equilibrium.value,
+        guides.join(", ")
+    )
+}
+
+fn mode_name(mode: ParseMode) -> &'static str {
+    match mode {
+        ParseMode::Move => "move",
+        ParseMode::Outcome => "outcome",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-6, "{} != {}", a, b);
+    }
+
+    /// Neither side can do better by switching to a single move.
+    fn assert_equilibrium(payoffs: &Payoffs, e: &Equilibrium) {
+        assert_close(e.player.iter().sum(), 1.0);
+        assert_close(e.opponent.iter().sum(), 1.0);
+        for score in payoffs.against_each(&e.player) {
+            assert!(score > e.value - 1e-6);
+        }
+        for row in &payoffs.scores {
+            let score: f64 = row.iter().zip(&e.opponent).map(|(s, q)| s * q).sum();
+            assert!(score < e.value + 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_symmetric_scores_are_uniform() {
+        let mut game = Game::rpsls();
+        game.move_scores = vec![0; 5];
+        let payoffs = Payoffs::of_game(&game);
+        let e = solve(&payoffs);
+        assert_equilibrium(&payoffs, &e);
+        assert_close(e.value, 3.0);
+        e.player.iter().for_each(|&p| assert_close(p, 0.2));
+    }
+
+    #[test]
+    fn test_puzzle_scores() {
+        let payoffs = Payoffs::puzzle();
+        let e = solve(&payoffs);
+        assert_equilibrium(&payoffs, &e);
+        // Every move scores 15 over the three opponent moves, so the player
+        // can do no better than uniform, but the shape scores skew the
+        // opponent away from Paper, which hands out the 9.
+        assert_close(e.value, 5.0);
+        e.player.iter().for_each(|&p| assert_close(p, 1.0 / 3.0));
+        for (q, expected) in e.opponent.iter().zip([4.0 / 9.0, 1.0 / 9.0, 4.0 / 9.0]) {
+            assert_close(*q, expected);
+        }
+        for game in [Game::rps(), Game::rps7(), Game::rps15()] {
+            let payoffs = Payoffs::of_game(&game);
+            assert_equilibrium(&payoffs, &solve(&payoffs));
+        }
+    }
+
+    #[test]
+    fn test_compare_example() {
+        let payoffs = Payoffs::puzzle();
+        // The example read as moves: Paper against Rock, Rock against Paper
+        // and Scissors against Scissors.
+        let c = compare(&payoffs, ParseMode::Move, &[(0, 1), (1, 0), (2, 2)]);
+        assert_close(c.average, 5.0);
+        // Playing each move a third of the time scores 5 against anything.
+        assert_close(c.guaranteed, (1.0 + 2.0 + 3.0) / 3.0 + 3.0);
+    }
+}
@@ -0,0 +1,219 @@
+//! Rock-paper-scissors generalised to any odd number of moves.
+
+use super::outcome::Outcome;
+use super::round::{columns, ParseError, ParseMode};
+use crate::error::AocError;
+
+/// A cyclic game: each move beats the `(n - 1) / 2` moves after it in
+/// `moves`, wrapping around, and loses against the rest.
+#[derive(Debug, Clone)]
+pub struct Game {
+    pub moves: Vec<String>,
+    /// Letter of each move in the first column of the guide.
+    pub opponent_letters: Vec<String>,
+    /// Letter of each move in the second column, in [`ParseMode::Move`].
+    pub player_letters: Vec<String>,
+    /// Letters of a loss, a draw and a win in [`ParseMode::Outcome`].
+    pub outcome_letters: [String; 3],
+    pub move_scores: Vec<i32>,
+    /// Scores of a loss, a draw and a win.
+    pub outcome_scores: [i32; 3],
+}
+
+fn index(outcome: Outcome) -> usize {
+    match outcome {
+        Outcome::Loss => 0,
+        Outcome::Draw => 1,
+        Outcome::Win => 2,
+    }
+}
+
+fn strings(items: &[&str]) -> Vec<String> {
+    items.iter().map(|s| s.to_string()).collect()
+}
+
+impl Game {
+    /// A game with the default mappings: the opponent's letters start at
+    /// `A`, the player's end at `Z`, and moves score 1, 2, 3... in order.
+    pub fn new(moves: &[&str]) -> Game {
+        let n = moves.len();
+        Game {
+            moves: strings(moves),
+            opponent_letters: (0..n).map(|i| ((b'A' + i as u8) as char).to_string()).collect(),
+            player_letters: (0..n).map(|i| ((b'Z' + i as u8 - n as u8 + 1) as char).to_string()).collect(),
+            outcome_letters: ["X".into(), "Y".into(), "Z".into()],
+            move_scores: (1..=n as i32).collect(),
+            outcome_scores: [0, 3, 6],
+        }
+    }
+
+    /// The game of the puzzle, with its letters and scores.
+    pub fn rps() -> Game {
+        Game {
+            opponent_letters: strings(&["A", "C", "B"]),
+            player_letters: strings(&["X", "Z", "Y"]),
+            move_scores: vec![1, 3, 2],
+            ..Game::new(&["Rock", "Scissors", "Paper"])
+        }
+    }
+
+    pub fn rpsls() -> Game {
+        Game::new(&["Rock", "Scissors", "Lizard", "Paper", "Spock"])
+    }
+
+    pub fn rps7() -> Game {
+        Game::new(&["Rock", "Fire", "Scissors", "Sponge", "Paper", "Air", "Water"])
+    }
+
+    pub fn rps15() -> Game {
+        Game::new(&[
+            "Rock", "Fire", "Scissors", "Snake", "Human", "Tree", "Wolf", "Sponge", "Paper", "Air", "Water",
+            "Dragon", "Devil", "Lightning", "Gun",
+        ])
+    }
+
+    /// Checks that the game is well formed and its mappings unambiguous.
+    pub fn validate(&self) -> Result<(), AocError> {
+        let n = self.moves.len();
+        let invalid = |reason: String| Err(AocError::Invalid(reason));
+        if n < 3 || n.is_multiple_of(2) {
+            return invalid(format!("a cyclic game needs an odd number of moves, not {}", n));
+        }
+        for (name, len) in [
+            ("opponent letters", self.opponent_letters.len()),
+            ("player letters", self.player_letters.len()),
+            ("move scores", self.move_scores.len()),
+        ] {
+            if len != n {
+                return invalid(format!("expected {} {}, found {}", n, name, len));
+            }
+        }
+        for (name, letters) in [
+            ("opponent", &self.opponent_letters[..]),
+            ("player", &self.player_letters[..]),
+            ("outcome", &self.outcome_letters[..]),
+        ] {
+            if let Some((i, letter)) = letters.iter().enumerate().find(|(i, l)| letters[..*i].contains(l)) {
+                return invalid(format!("{} letter {:?} is used twice (at {})", name, letter, i + 1));
+            }
+        }
+        Ok(())
+    }
+
+    /// The outcome for the player of `player` against `opponent`.
+    pub fn outcome(&self, player: usize, opponent: usize) -> Outcome {
+        let n = self.moves.len();
+        match (opponent + n - player) % n {
+            0 => Outcome::Draw,
+            d if d <= (n - 1) / 2 => Outcome::Win,
+            _ => Outcome::Loss,
+        }
+    }
+
+    /// A move reaching `outcome` against `opponent`. When several do, the
+    /// one next to the opponent's in the cycle is chosen.
+    pub fn player_move(&self, opponent: usize, outcome: Outcome) -> usize {
+        let n = self.moves.len();
+        match outcome {
+            Outcome::Draw => opponent,
+            Outcome::Win => (opponent + n - 1) % n,
+            Outcome::Loss => (opponent + 1) % n,
+        }
+    }
+
+    pub fn score(&self, player: usize, opponent: usize) -> i32 {
+        self.move_scores[player] + self.outcome_scores[index(self.outcome(player, opponent))]
+    }
+
+    /// The opponent's and the player's move on a line of the guide.
+    pub fn parse_round(&self, line: &str, mode: ParseMode) -> Result<(usize, usize), ParseError> {
+        let (first, second) = columns(line)?;
+        let find = |letters: &[String], s: &str| letters.iter().position(|l| l == s);
+        let opponent = find(&self.opponent_letters, first).ok_or_else(|| ParseError::Opponent(first.to_string()))?;
+        let player = match mode {
+            ParseMode::Move => find(&self.player_letters, second).ok_or_else(|| ParseError::Player(second.to_string()))?,
+            ParseMode::Outcome => {
+                let outcome = find(&self.outcome_letters, second).ok_or_else(|| ParseError::Outcome(second.to_string()))?;
+                self.player_move(opponent, [Outcome::Loss, Outcome::Draw, Outcome::Win][outcome])
+            }
+        };
+        Ok((opponent, player))
+    }
+
+    /// The opponent's and the player's move on every line of the guide,
+    /// or all the lines that could not be read.
+    pub fn parse_guide(&self, input: &str, mode: ParseMode) -> Result<Vec<(usize, usize)>, AocError> {
+        self.validate()?;
+        let (mut rounds, mut errors) = (vec![], vec![]);
+        for (i, line) in input.lines().enumerate() {
+            match self.parse_round(line, mode) {
+                Ok(round) => rounds.push(round),
+                Err(e) => errors.push(AocError::parse(i + 1, e.column(line), e)),
+            }
+        }
+        AocError::collect(rounds, errors)
+    }
+
+    /// The total score of following the guide.
+    pub fn score_guide(&self, input: &str, mode: ParseMode) -> Result<i32, AocError> {
+        let rounds = self.parse_guide(input, mode)?;
+        Ok(rounds.into_iter().map(|(opponent, player)| self.score(player, opponent)).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day02::{Day02, EXAMPLE};
+    use crate::solution::Solution;
+
+    #[test]
+    fn test_rps_matches_the_puzzle() {
+        let game = Game::rps();
+        assert_eq!(game.score_guide(EXAMPLE, ParseMode::Move).unwrap(), 15);
+        assert_eq!(game.score_guide(EXAMPLE, ParseMode::Outcome).unwrap(), 12);
+        for seed in 0..10 {
+            let input = crate::generate::generate(2, seed, 100).unwrap().input;
+            let strategies = Day02::parse(&input).unwrap();
+            assert_eq!(game.score_guide(&input, ParseMode::Move).unwrap(), Day02::part1(&strategies).unwrap());
+            assert_eq!(game.score_guide(&input, ParseMode::Outcome).unwrap(), Day02::part2(&strategies).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_every_move_wins_half() {
+        for game in [Game::rps(), Game::rpsls(), Game::rps7(), Game::rps15()] {
+            game.validate().unwrap();
+            let n = game.moves.len();
+            for player in 0..n {
+                let wins = (0..n).filter(|&o| game.outcome(player, o) == Outcome::Win).count();
+                assert_eq!(wins, (n - 1) / 2, "{}", game.moves[player]);
+                for opponent in 0..n {
+                    assert_eq!(game.outcome(player, opponent) == Outcome::Win, game.outcome(opponent, player) == Outcome::Loss);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rpsls() {
+        let game = Game::rpsls();
+        let at = |name: &str| game.moves.iter().position(|m| m == name).unwrap();
+        assert_eq!(game.outcome(at("Spock"), at("Scissors")), Outcome::Win);
+        assert_eq!(game.outcome(at("Lizard"), at("Spock")), Outcome::Win);
+        assert_eq!(game.outcome(at("Paper"), at("Scissors")), Outcome::Loss);
+        assert_eq!(game.player_letters, vec!["V", "W", "X", "Y", "Z"]);
+        // Rock (V) against Lizard (C) wins, Lizard (X) against Rock (A) loses.
+        assert_eq!(game.score_guide("C V\nA X\n", ParseMode::Move).unwrap(), 7 + 3);
+        let lose = game.player_move(at("Spock"), Outcome::Loss);
+        assert_eq!(game.outcome(lose, at("Spock")), Outcome::Loss);
+    }
+
+    #[test]
+    fn test_invalid_games() {
+        assert!(Game::new(&["Rock", "Paper"]).validate().is_err());
+        let mut game = Game::rps();
+        game.player_letters[1] = "X".into();
+        assert!(game.validate().is_err());
+    }
+}
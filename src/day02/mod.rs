@@ -0,0 +1,56 @@
+pub mod cipher;
+pub mod counter;
+pub mod equilibrium;
+pub mod game;
+pub mod moves;
+pub mod outcome;
+pub mod round;
+pub mod strategy;
+pub mod tournament;
+pub mod trace;
+
+#[cfg(test)]
+mod test;
+
+use crate::error::AocError;
+use crate::solution::Solution;
+use round::{OutcomeRound, ParseMode, PlayerRound};
+use strategy::{parse_rounds, Guide, OutcomeStrategy, PlayerStrategy};
+
+/// The example from the puzzle description.
+pub const EXAMPLE: &str = include_str!("../../input/day02-example.txt");
+
+pub struct Day02;
+
+impl Day02 {
+    /// The guide read in both parse modes.
+    pub fn strategies(input: &str) -> Result<(PlayerStrategy, OutcomeStrategy), AocError> {
+        Ok((
+            PlayerStrategy { rounds: parse_rounds::<PlayerRound>(input)? },
+            OutcomeStrategy { rounds: parse_rounds::<OutcomeRound>(input)? },
+        ))
+    }
+}
+
+/// Each part reads the second column of the guide its own way: part 1 as the
+/// player's move, part 2 as the desired outcome. The input is kept as is so
+/// that each part reports the errors of its own reading.
+impl Solution for Day02 {
+    type Input = String;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(guide: &String) -> Result<i32, AocError> {
+        Ok(Guide::parse(guide, ParseMode::Move)?.score())
+    }
+
+    fn part2(guide: &String) -> Result<i32, AocError> {
+        Ok(Guide::parse(guide, ParseMode::Outcome)?.score())
+    }
+}
+
+crate::example_tests!(Day02, example: EXAMPLE => (15, 12));
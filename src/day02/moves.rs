@@ -0,0 +1,81 @@
+use std::str::FromStr;
+
+use super::outcome::Outcome;
+use super::round::ParseError;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum Move {
+    Rock,
+    Paper,
+    Scissors
+}
+
+impl Move {
+    pub const ALL: [Move; 3] = [Move::Rock, Move::Paper, Move::Scissors];
+
+    pub fn player_score(self) -> i32 {
+        match self {
+            Move::Rock => 1,
+            Move::Paper => 2,
+            Move::Scissors => 3
+        }
+    }
+
+    /// The move this one wins against.
+    pub fn beats(self) -> Move {
+        match self {
+            Move::Rock => Move::Scissors,
+            Move::Paper => Move::Rock,
+            Move::Scissors => Move::Paper
+        }
+    }
+
+    /// The move this one loses against.
+    pub fn beaten_by(self) -> Move {
+        match self {
+            Move::Rock => Move::Paper,
+            Move::Paper => Move::Scissors,
+            Move::Scissors => Move::Rock
+        }
+    }
+
+    /// The outcome of playing this move against `opponent`.
+    pub fn against(self, opponent: Move) -> Outcome {
+        if self == opponent {
+            Outcome::Draw
+        } else if self.beats() == opponent {
+            Outcome::Win
+        } else {
+            Outcome::Loss
+        }
+    }
+
+    /// Reads the player's move from the second column of the guide.
+    pub fn from_player(s: &str) -> Result<Move, ParseError> {
+        match s {
+            "X" => Ok(Move::Rock),
+            "Y" => Ok(Move::Paper),
+            "Z" => Ok(Move::Scissors),
+            _ => Err(ParseError::Player(s.to_string()))
+        }
+    }
+}
+
+/// Reads the opponent's move from the first column of the guide.
+impl FromStr for Move {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" => Ok(Move::Rock),
+            "B" => Ok(Move::Paper),
+            "C" => Ok(Move::Scissors),
+            _ => Err(ParseError::Opponent(s.to_string()))
+        }
+    }
+}
+
+impl From<Move> for i32 {
+    fn from(shape: Move) -> Self {
+        shape.player_score()
+    }
+}
@@ -1,19 +1,17 @@
 use std::str::FromStr;
 
-#[derive(Debug, thiserror::Error)]
-pub enum ParseError {
-    #[error("Invalid outcome")]
-    Outcome(String)
-}
+use super::round::ParseError;
 
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
 pub enum Outcome {
-    Loss = 0,
-    Draw = 3,
-    Win = 6,
+    Loss,
+    Draw,
+    Win,
 }
 
 impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+
     pub fn score(self) -> i32 {
         match self {
             Outcome::Loss => 0,
@@ -23,6 +21,7 @@ impl Outcome {
     }
 }
 
+/// Reads the desired outcome from the second column of the guide.
 impl FromStr for Outcome {
     type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -0,0 +1,121 @@
+use std::str::FromStr;
+use super::moves::Move;
+use super::outcome::Outcome;
+
+/// How the second column of the strategy guide is read.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ParseMode {
+    /// `X`, `Y` and `Z` are the player's move.
+    Move,
+    /// `X`, `Y` and `Z` are the outcome the player has to reach.
+    Outcome,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ParseError {
+    #[error("Invalid opponent move {0:?}")]
+    Opponent(String),
+    #[error("Invalid player move {0:?}")]
+    Player(String),
+    #[error("Invalid outcome {0:?}")]
+    Outcome(String),
+    #[error(
+        "Expected 2 columns, found {found}{}",
+        extra.as_ref().map_or(String::new(), |token| format!(" (extra {:?})", token))
+    )]
+    Columns { found: usize, extra: Option<String> },
+}
+
+impl ParseError {
+    /// 1-based column of the error on `line`: the offending column, the
+    /// first extra one, or the end of the line when one is missing.
+    pub fn column(&self, line: &str) -> usize {
+        let at = |n: usize| tokens(line).nth(n).map_or(line.len() + 1, |(i, _)| i + 1);
+        match self {
+            ParseError::Opponent(_) => at(0),
+            ParseError::Player(_) | ParseError::Outcome(_) => at(1),
+            ParseError::Columns { .. } => at(2),
+        }
+    }
+}
+
+/// The whitespace separated tokens of `line`, with their byte offsets.
+fn tokens(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.split_whitespace().map(move |token| (token.as_ptr() as usize - line.as_ptr() as usize, token))
+}
+
+/// The two columns of a line of the guide, which must have exactly two.
+pub fn columns(round: &str) -> Result<(&str, &str), ParseError> {
+    match round.split_whitespace().collect::<Vec<_>>()[..] {
+        [opponent, second] => Ok((opponent, second)),
+        ref other => Err(ParseError::Columns { found: other.len(), extra: other.get(2).map(|t| t.to_string()) }),
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PlayerRound {
+    pub opponent: Move,
+    pub player: Move,
+}
+
+impl PlayerRound {
+    pub fn outcome(self) -> Outcome {
+        self.player.against(self.opponent)
+    }
+
+    pub fn score(self) -> i32 {
+        self.player.player_score() + self.outcome().score()
+    }
+}
+
+/// Reads a line of the guide in [`ParseMode::Move`].
+impl FromStr for PlayerRound {
+    type Err = ParseError;
+
+    fn from_str(round: &str) -> Result<Self, ParseError> {
+        let (opponent, player) = columns(round)?;
+        Ok(PlayerRound { opponent: opponent.parse()?, player: Move::from_player(player)? })
+    }
+}
+
+impl From<PlayerRound> for i32 {
+    fn from(round: PlayerRound) -> Self {
+        round.score()
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct OutcomeRound {
+    pub opponent: Move,
+    pub outcome: Outcome
+}
+
+impl OutcomeRound {
+    pub fn player_move(self) -> Move {
+        match self.outcome {
+            Outcome::Draw => self.opponent,
+            Outcome::Win => self.opponent.beaten_by(),
+            Outcome::Loss => self.opponent.beats()
+        }
+    }
+
+    pub fn score(self) -> i32 {
+        PlayerRound::from(self).score()
+    }
+}
+
+/// Reads a line of the guide in [`ParseMode::Outcome`].
+impl FromStr for OutcomeRound {
+    type Err = ParseError;
+
+    fn from_str(round: &str) -> Result<Self, ParseError> {
+        let (opponent, outcome) = columns(round)?;
+        Ok(OutcomeRound { opponent: opponent.parse()?, outcome: outcome.parse()? })
+    }
+}
+
+impl From<OutcomeRound> for PlayerRound {
+    fn from(value: OutcomeRound) -> Self {
+        PlayerRound { opponent: value.opponent, player: value.player_move() }
+    }
+}
@@ -0,0 +1,106 @@
+use std::str::FromStr;
+use super::round;
+use super::round::{OutcomeRound, ParseMode, PlayerRound};
+use crate::error::AocError;
+
+pub struct OutcomeStrategy {
+    pub rounds: Vec<OutcomeRound>
+}
+
+impl OutcomeStrategy {
+    pub fn score(&self) -> i32 {
+        self.rounds.iter().map(|round| round.score()).sum()
+    }
+}
+
+impl FromStr for OutcomeStrategy {
+    type Err = round::ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rounds: Vec<OutcomeRound> = s
+            .lines()
+            .map(|l| l.parse::<OutcomeRound>())
+            .collect::<Result<_, _>>()?;
+        Ok(OutcomeStrategy { rounds })
+    }
+}
+
+pub struct PlayerStrategy {
+    pub rounds: Vec<PlayerRound>,
+}
+
+impl PlayerStrategy {
+    pub fn score(&self) -> i32 {
+        self.rounds.iter().map(|round| round.score()).sum()
+    }
+}
+
+impl FromStr for PlayerStrategy {
+    type Err = round::ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rounds: Vec<PlayerRound> = s
+            .lines()
+            .map(|l| l.parse::<PlayerRound>())
+            .collect::<Result<_, _>>()?;
+        Ok(PlayerStrategy { rounds })
+    }
+}
+
+impl From<PlayerStrategy> for i32 {
+    fn from(strategy: PlayerStrategy) -> Self {
+        strategy
+            .rounds
+            .into_iter()
+            .map(<PlayerRound as std::convert::Into<i32>>::into)
+            .sum()
+    }
+}
+
+impl From<OutcomeStrategy> for PlayerStrategy {
+    fn from(value: OutcomeStrategy) -> Self {
+        let rounds: Vec<PlayerRound> = value.rounds.iter().map(|r| <OutcomeRound as std::convert::Into<PlayerRound>>::into(*r)).collect();
+        PlayerStrategy { rounds }
+    }
+}
+
+/// Parses every line of the guide, reporting all the errors with their
+/// position.
+pub fn parse_rounds<R: FromStr<Err = round::ParseError>>(input: &str) -> Result<Vec<R>, AocError> {
+    let (mut rounds, mut errors) = (vec![], vec![]);
+    for (i, line) in input.lines().enumerate() {
+        match line.parse::<R>() {
+            Ok(round) => rounds.push(round),
+            Err(e) => errors.push(AocError::parse(i + 1, e.column(line), e)),
+        }
+    }
+    AocError::collect(rounds, errors)
+}
+
+/// The strategy guide read in one of the two parse modes.
+pub enum Guide {
+    Moves(PlayerStrategy),
+    Outcomes(OutcomeStrategy),
+}
+
+impl Guide {
+    pub fn parse(input: &str, mode: ParseMode) -> Result<Guide, AocError> {
+        Ok(match mode {
+            ParseMode::Move => Guide::Moves(PlayerStrategy { rounds: parse_rounds(input)? }),
+            ParseMode::Outcome => Guide::Outcomes(OutcomeStrategy { rounds: parse_rounds(input)? }),
+        })
+    }
+
+    /// The moves the player makes following the guide.
+    pub fn player_rounds(&self) -> Vec<PlayerRound> {
+        match self {
+            Guide::Moves(strategy) => strategy.rounds.clone(),
+            Guide::Outcomes(strategy) => strategy.rounds.iter().map(|&r| r.into()).collect(),
+        }
+    }
+
+    pub fn score(&self) -> i32 {
+        match self {
+            Guide::Moves(strategy) => strategy.score(),
+            Guide::Outcomes(strategy) => strategy.score(),
+        }
+    }
+}
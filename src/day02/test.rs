@@ -0,0 +1,196 @@
+use std::str::FromStr;
+
+use super::moves::Move;
+use super::outcome::Outcome;
+use super::round::{OutcomeRound, ParseError, ParseMode, PlayerRound};
+use super::strategy::{parse_rounds, Guide};
+use crate::error::{self, AocError};
+
+#[test]
+fn test_outcome_round_to_player_round_draw() {
+    let outcome_round = OutcomeRound {
+        opponent: Move::Rock,
+        outcome: Outcome::Draw
+    };
+    let player_round: PlayerRound = outcome_round.into();
+    let expected: PlayerRound = PlayerRound { opponent: Move::Rock, player: Move::Rock };
+    assert_eq!(player_round, expected);
+}
+#[test]
+fn test_outcome_round_to_player_round_win() {
+    let outcome_round = OutcomeRound {
+        opponent: Move::Rock,
+        outcome: Outcome::Win
+    };
+    let player_round: PlayerRound = outcome_round.into();
+    let expected: PlayerRound = PlayerRound { opponent: Move::Rock, player: Move::Paper };
+    assert_eq!(player_round, expected);
+}
+#[test]
+fn test_outcome_round_to_player_round_loss() {
+    let outcome_round = OutcomeRound {
+        opponent: Move::Rock,
+        outcome: Outcome::Loss
+    };
+    let player_round: PlayerRound = outcome_round.into();
+    let expected: PlayerRound = PlayerRound { opponent: Move::Rock, player: Move::Scissors };
+    assert_eq!(player_round, expected);
+}
+#[test]
+fn test_outcome_round_to_player_round_draw2() {
+    let outcome_round = OutcomeRound {
+        opponent: Move::Paper,
+        outcome: Outcome::Draw
+    };
+    let player_round: PlayerRound = outcome_round.into();
+    let expected: PlayerRound = PlayerRound { opponent: Move::Paper, player: Move::Paper };
+    assert_eq!(player_round, expected);
+}
+#[test]
+fn test_outcome_round_to_player_round_win2() {
+    let outcome_round = OutcomeRound {
+        opponent: Move::Paper,
+        outcome: Outcome::Win
+    };
+    let player_round: PlayerRound = outcome_round.into();
+    let expected: PlayerRound = PlayerRound { opponent: Move::Paper, player: Move::Scissors };
+    assert_eq!(player_round, expected);
+}
+#[test]
+fn test_outcome_round_to_player_round_loss2() {
+    let outcome_round = OutcomeRound {
+        opponent: Move::Paper,
+        outcome: Outcome::Loss
+    };
+    let player_round: PlayerRound = outcome_round.into();
+    let expected: PlayerRound = PlayerRound { opponent: Move::Paper, player: Move::Rock };
+    assert_eq!(player_round, expected);
+}
+
+#[test]
+fn test_outcome_round_to_player_round_draw3() {
+    let outcome_round = OutcomeRound {
+        opponent: Move::Scissors,
+        outcome: Outcome::Draw
+    };
+    let player_round: PlayerRound = outcome_round.into();
+    let expected: PlayerRound = PlayerRound { opponent: Move::Scissors, player: Move::Scissors };
+    assert_eq!(player_round, expected);
+}
+#[test]
+fn test_outcome_round_to_player_round_win3() {
+    let outcome_round = OutcomeRound {
+        opponent: Move::Scissors,
+        outcome: Outcome::Win
+    };
+    let player_round: PlayerRound = outcome_round.into();
+    let expected: PlayerRound = PlayerRound { opponent: Move::Scissors, player: Move::Rock };
+    assert_eq!(player_round, expected);
+}
+#[test]
+fn test_outcome_round_to_player_round_loss3() {
+    let outcome_round = OutcomeRound {
+        opponent: Move::Scissors,
+        outcome: Outcome::Loss
+    };
+    let player_round: PlayerRound = outcome_round.into();
+    let expected: PlayerRound = PlayerRound { opponent: Move::Scissors, player: Move::Paper };
+    assert_eq!(player_round, expected);
+}
+
+
+#[test]
+fn test_score() {
+    let outcome_round = OutcomeRound {
+        opponent: FromStr::from_str("A").unwrap(),
+        outcome: FromStr::from_str("Y").unwrap()
+    };
+    let player_round: PlayerRound = outcome_round.into();
+    let calculated_score: i32 = player_round.into();
+    let expected_score: i32 = 1+3;
+    assert_eq!(calculated_score, expected_score);
+}
+
+#[test]
+fn test_round_outcome() {
+    assert_eq!(PlayerRound { opponent: Move::Rock, player: Move::Rock }.outcome(), Outcome::Draw);
+    assert_eq!(PlayerRound { opponent: Move::Rock, player: Move::Paper }.outcome(), Outcome::Win);
+    assert_eq!(PlayerRound { opponent: Move::Rock, player: Move::Scissors }.outcome(), Outcome::Loss);
+}
+
+#[test]
+fn test_player_scores() {
+    assert_eq!(Move::Rock.player_score(), 1);
+    assert_eq!(Move::Paper.player_score(), 2);
+    assert_eq!(Move::Scissors.player_score(), 3);
+}
+
+#[test]
+fn test_comparison_of_moves() {
+    assert_eq!(Move::Rock.beats(), Move::Scissors);
+    assert_eq!(Move::Scissors.beaten_by(), Move::Rock);
+}
+
+#[test]
+fn test_parse_modes() {
+    let line = "A Z";
+    let as_move: PlayerRound = line.parse().unwrap();
+    let as_outcome: OutcomeRound = line.parse().unwrap();
+    assert_eq!(as_move.player, Move::Scissors);
+    assert_eq!(as_outcome.outcome, Outcome::Win);
+    assert_eq!(as_move.score(), 3);
+    assert_eq!(as_outcome.score(), 8);
+}
+
+#[test]
+fn test_guide_parse_modes() {
+    let moves = Guide::parse(super::EXAMPLE, ParseMode::Move).unwrap();
+    let outcomes = Guide::parse(super::EXAMPLE, ParseMode::Outcome).unwrap();
+    assert_eq!((moves.score(), outcomes.score()), (15, 12));
+    assert_eq!(outcomes.player_rounds()[0], PlayerRound { opponent: Move::Rock, player: Move::Rock });
+}
+
+#[test]
+fn test_lines_need_two_columns() {
+    let error = PlayerRound::from_str("A Y Z").unwrap_err();
+    assert_eq!(error.to_string(), "Expected 2 columns, found 3 (extra \"Z\")");
+    let error = OutcomeRound::from_str("A").unwrap_err();
+    assert_eq!(error.to_string(), "Expected 2 columns, found 1");
+    assert!(matches!(PlayerRound::from_str(""), Err(ParseError::Columns { found: 0, extra: None })));
+    assert!(PlayerRound::from_str("  A \t Y ").is_ok());
+}
+
+#[test]
+fn test_parse_error_columns() {
+    let error = PlayerRound::from_str("A A").unwrap_err();
+    assert!(matches!(error, ParseError::Player(_)));
+    assert_eq!(error.column("A A"), 3);
+    assert_eq!(OutcomeRound::from_str("D Y").unwrap_err().column("D Y"), 1);
+    assert_eq!(PlayerRound::from_str("A Y  Z").unwrap_err().column("A Y  Z"), 6);
+    assert_eq!(PlayerRound::from_str("A ").unwrap_err().column("A "), 3);
+}
+
+#[test]
+fn test_all_errors_are_reported() {
+    let Err(AocError::Many(errors)) = parse_rounds::<PlayerRound>("A Y\nA W\nB X\nQ Z Z\n") else {
+        panic!("expected several errors");
+    };
+    assert!(matches!(errors[0], AocError::Parse { line: 2, column: 3, source: error::ParseError::Round(ParseError::Player(_)) }));
+    assert!(matches!(errors[1], AocError::Parse { line: 4, column: 5, source: error::ParseError::Round(ParseError::Columns { found: 3, .. }) }));
+    assert_eq!(errors.len(), 2);
+    let single = parse_rounds::<OutcomeRound>("A Y\nC Q\n").err().unwrap();
+    assert_eq!(single.to_string(), "line 2, column 3: Invalid outcome \"Q\"");
+}
+
+#[test]
+fn test_each_part_reports_its_own_reading() {
+    use crate::runner::{run, Part};
+    use crate::solution::Solution;
+
+    let report = run::<super::Day02>("A Y\nC Q\n", &[Part::One, Part::Two]).unwrap();
+    let errors: Vec<AocError> = report.answers.into_iter().map(|a| a.value.unwrap_err()).collect();
+    assert!(matches!(errors[0], AocError::Parse { line: 2, column: 3, source: error::ParseError::Round(ParseError::Player(_)) }));
+    assert!(matches!(errors[1], AocError::Parse { line: 2, column: 3, source: error::ParseError::Round(ParseError::Outcome(_)) }));
+    let guide = super::Day02::parse("A Y\nB Z\n").unwrap();
+    assert_eq!(super::Day02::part2(&guide).unwrap(), 4 + 9);
+}
@@ -0,0 +1,266 @@
+//! Bots playing rock-paper-scissors against each other.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use super::moves::Move;
+use super::outcome::Outcome;
+use super::round::PlayerRound;
+use crate::generate::Rng;
+
+/// A player choosing its moves from the rounds played so far. In the
+/// history the bot is always the `player` and the other side the `opponent`.
+pub trait Bot {
+    fn next_move(&mut self, history: &[PlayerRound]) -> Move;
+}
+
+pub struct Constant(pub Move);
+
+impl Bot for Constant {
+    fn next_move(&mut self, _: &[PlayerRound]) -> Move {
+        self.0
+    }
+}
+
+/// Plays the given moves over and over.
+pub struct Cycle(pub Vec<Move>);
+
+impl Bot for Cycle {
+    fn next_move(&mut self, history: &[PlayerRound]) -> Move {
+        self.0[history.len() % self.0.len()]
+    }
+}
+
+pub struct Random(pub Rng);
+
+impl Bot for Random {
+    fn next_move(&mut self, _: &[PlayerRound]) -> Move {
+        self.0.choose(&Move::ALL)
+    }
+}
+
+/// The first of the moves with the highest count, or rock if none.
+fn most_common(counts: &HashMap<Move, usize>) -> Move {
+    Move::ALL
+        .into_iter()
+        .fold((Move::Rock, 0), |(best, most), m| {
+            let count = counts.get(&m).copied().unwrap_or(0);
+            if count > most { (m, count) } else { (best, most) }
+        })
+        .0
+}
+
+/// Beats the opponent's most frequent move.
+pub struct FrequencyCounter;
+
+impl Bot for FrequencyCounter {
+    fn next_move(&mut self, history: &[PlayerRound]) -> Move {
+        let mut counts = HashMap::new();
+        for round in history {
+            *counts.entry(round.opponent).or_default() += 1;
+        }
+        most_common(&counts).beaten_by()
+    }
+}
+
+/// Predicts the opponent's move from the one it played last, using how
+/// often each move has followed it so far.
+pub struct Markov;
+
+impl Bot for Markov {
+    fn next_move(&mut self, history: &[PlayerRound]) -> Move {
+        let Some(last) = history.last() else {
+            return Move::Rock;
+        };
+        let mut counts = HashMap::new();
+        for pair in history.windows(2) {
+            if pair[0].opponent == last.opponent {
+                *counts.entry(pair[1].opponent).or_default() += 1;
+            }
+        }
+        most_common(&counts).beaten_by()
+    }
+}
+
+/// Keeps a winning move, and otherwise switches to the one beating it.
+pub struct WinStayLoseShift;
+
+impl Bot for WinStayLoseShift {
+    fn next_move(&mut self, history: &[PlayerRound]) -> Move {
+        match history.last() {
+            None => Move::Rock,
+            Some(last) if last.outcome() == Outcome::Win => last.player,
+            Some(last) => last.player.beaten_by(),
+        }
+    }
+}
+
+/// Plays the player's moves of a strategy guide, starting over at the end.
+pub fn replay(rounds: &[PlayerRound]) -> Cycle {
+    Cycle(rounds.iter().map(|r| r.player).collect())
+}
+
+/// A bot in a tournament. Every match starts from a fresh bot.
+pub struct Entrant {
+    pub name: String,
+    pub make: Box<dyn Fn() -> Box<dyn Bot>>,
+}
+
+impl Entrant {
+    pub fn new(name: &str, make: impl Fn() -> Box<dyn Bot> + 'static) -> Entrant {
+        Entrant { name: name.to_string(), make: Box::new(make) }
+    }
+}
+
+/// The built-in bots, plus one replaying `guide` if given.
+pub fn entrants(seed: u64, guide: Option<Vec<PlayerRound>>) -> Vec<Entrant> {
+    let mut entrants = vec![
+        Entrant::new("rock", || Box::new(Constant(Move::Rock))),
+        Entrant::new("cycle", || Box::new(Cycle(Move::ALL.to_vec()))),
+        Entrant::new("random", move || Box::new(Random(Rng::new(seed)))),
+        Entrant::new("frequency", || Box::new(FrequencyCounter)),
+        Entrant::new("markov", || Box::new(Markov)),
+        Entrant::new("win-stay-lose-shift", || Box::new(WinStayLoseShift)),
+    ];
+    if let Some(rounds) = guide.filter(|rounds| !rounds.is_empty()) {
+        entrants.push(Entrant::new("guide", move || Box::new(replay(&rounds))));
+    }
+    entrants
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+pub struct MatchResult {
+    /// Total shape and outcome scores of each side.
+    pub scores: (i32, i32),
+    pub wins: (usize, usize),
+    pub draws: usize,
+}
+
+pub fn play(a: &mut dyn Bot, b: &mut dyn Bot, rounds: usize) -> MatchResult {
+    let (mut history_a, mut history_b) = (vec![], vec![]);
+    let mut result = MatchResult::default();
+    for _ in 0..rounds {
+        let (move_a, move_b) = (a.next_move(&history_a), b.next_move(&history_b));
+        let round_a = PlayerRound { opponent: move_b, player: move_a };
+        let round_b = PlayerRound { opponent: move_a, player: move_b };
+        result.scores.0 += round_a.score();
+        result.scores.1 += round_b.score();
+        match round_a.outcome() {
+            Outcome::Win => result.wins.0 += 1,
+            Outcome::Loss => result.wins.1 += 1,
+            Outcome::Draw => result.draws += 1,
+        }
+        history_a.push(round_a);
+        history_b.push(round_b);
+    }
+    result
+}
+
+/// A line of the league table. Matches are won on total score and give 3
+/// points for a win and 1 for a draw.
+#[derive(Debug, Default, Clone)]
+pub struct Standing {
+    pub name: String,
+    pub won: usize,
+    pub drawn: usize,
+    pub lost: usize,
+    pub score_for: i32,
+    pub score_against: i32,
+    pub points: usize,
+}
+
+impl Standing {
+    fn record(&mut self, score_for: i32, score_against: i32) {
+        self.score_for += score_for;
+        self.score_against += score_against;
+        if score_for > score_against {
+            self.won += 1;
+            self.points += 3;
+        } else if score_for == score_against {
+            self.drawn += 1;
+            self.points += 1;
+        } else {
+            self.lost += 1;
+        }
+    }
+}
+
+/// Every entrant plays every other once; the table is sorted by points,
+/// then by score difference.
+pub fn round_robin(entrants: &[Entrant], rounds: usize) -> Vec<Standing> {
+    let mut table: Vec<Standing> = entrants
+        .iter()
+        .map(|e| Standing { name: e.name.clone(), ..Standing::default() })
+        .collect();
+    for i in 0..entrants.len() {
+        for j in i + 1..entrants.len() {
+            let result = play(&mut *(entrants[i].make)(), &mut *(entrants[j].make)(), rounds);
+            table[i].record(result.scores.0, result.scores.1);
+            table[j].record(result.scores.1, result.scores.0);
+        }
+    }
+    table.sort_by_key(|s| (std::cmp::Reverse(s.points), std::cmp::Reverse(s.score_for - s.score_against)));
+    table
+}
+
+pub fn to_table(table: &[Standing]) -> String {
+    let width = table.iter().map(|s| s.name.len()).max().unwrap_or(0).max(4);
+    let mut out = format!(
+        "{:>3}  {:<width$}  {:>3}  {:>3}  {:>3}  {:>10}  {:>10}  {:>6}\n",
+        "#", "Bot", "W", "D", "L", "For", "Against", "Points"
+    );
+    for (i, s) in table.iter().enumerate() {
+        writeln!(
+            out,
+            "{:>3}  {:<width$}  {:>3}  {:>3}  {:>3}  {:>10}  {:>10}  {:>6}",
+            i + 1,
+            s.name,
+            s.won,
+            s.drawn,
+            s.lost,
+            s.score_for,
+            s.score_against,
+            s.points
+        )
+        .unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constant_match() {
+        let result = play(&mut Constant(Move::Paper), &mut Constant(Move::Rock), 10);
+        assert_eq!(result, MatchResult { scores: (80, 10), wins: (10, 0), draws: 0 });
+    }
+
+    #[test]
+    fn test_predictors_beat_patterns() {
+        let result = play(&mut FrequencyCounter, &mut Constant(Move::Scissors), 100);
+        // Paper on the first round, before anything is known.
+        assert_eq!(result.wins, (99, 1));
+        let result = play(&mut Markov, &mut Cycle(Move::ALL.to_vec()), 300);
+        assert!(result.wins.0 > 290, "{:?}", result);
+    }
+
+    #[test]
+    fn test_win_stay_lose_shift() {
+        let mut bot = WinStayLoseShift;
+        let lost = PlayerRound { opponent: Move::Paper, player: Move::Rock };
+        assert_eq!(bot.next_move(&[lost]), Move::Paper);
+        let won = PlayerRound { opponent: Move::Rock, player: Move::Paper };
+        assert_eq!(bot.next_move(&[lost, won]), Move::Paper);
+    }
+
+    #[test]
+    fn test_round_robin() {
+        let table = round_robin(&entrants(1, None), 200);
+        assert_eq!(table.len(), 6);
+        assert_eq!(table.iter().map(|s| s.won + s.drawn + s.lost).sum::<usize>(), 6 * 5);
+        assert_eq!(table.iter().map(|s| s.won).sum::<usize>(), table.iter().map(|s| s.lost).sum::<usize>());
+        assert_eq!(table.last().unwrap().name, "rock");
+    }
+}
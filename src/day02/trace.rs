@@ -0,0 +1,107 @@
+//! Round by round account of how a guide is scored.
+
+use std::fmt::Write;
+
+use super::moves::Move;
+use super::outcome::Outcome;
+use super::round::PlayerRound;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Step {
+    /// Numbered from 1, like the lines of the guide.
+    pub round: usize,
+    pub opponent: Move,
+    pub player: Move,
+    pub outcome: Outcome,
+    pub shape_score: i32,
+    pub outcome_score: i32,
+    /// Score of this round and all the previous ones.
+    pub total: i32,
+}
+
+pub fn trace(rounds: &[PlayerRound]) -> Vec<Step> {
+    let mut total = 0;
+    rounds
+        .iter()
+        .enumerate()
+        .map(|(i, round)| {
+            let outcome = round.outcome();
+            total += round.score();
+            Step {
+                round: i + 1,
+                opponent: round.opponent,
+                player: round.player,
+                outcome,
+                shape_score: round.player.player_score(),
+                outcome_score: outcome.score(),
+                total,
+            }
+        })
+        .collect()
+}
+
+pub fn to_table(steps: &[Step]) -> String {
+    let mut out = format!(
+        "{:>6}  {:<9}  {:<9}  {:<7}  {:>5}  {:>7}  {:>8}\n",
+        "Round", "Opponent", "Player", "Outcome", "Shape", "Outcome", "Total"
+    );
+    for s in steps {
+        writeln!(
+            out,
+            "{:>6}  {:<9}  {:<9}  {:<7}  {:>5}  {:>7}  {:>8}",
+            s.round,
+            format!("{:?}", s.opponent),
+            format!("{:?}", s.player),
+            format!("{:?}", s.outcome),
+            s.shape_score,
+            s.outcome_score,
+            s.total
+        )
+        .unwrap();
+    }
+    out
+}
+
+/// One JSON object per round.
+pub fn to_json_lines(steps: &[Step]) -> String {
+    let mut out = String::new();
+    for s in steps {
+        writeln!(
+            out,
+            "{{\"round\": {}, \"opponent\": \"{:?}\", \"player\": \"{:?}\", \"outcome\": \"{:?}\", \"shape_score\": {}, \"outcome_score\": {}, \"total\": {}}}",
+            s.round, s.opponent, s.player, s.outcome, s.shape_score, s.outcome_score, s.total
+        )
+        .unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day02::round::ParseMode;
+    use crate::day02::strategy::Guide;
+    use crate::day02::EXAMPLE;
+
+    #[test]
+    fn test_trace_example() {
+        let guide = Guide::parse(EXAMPLE, ParseMode::Outcome).unwrap();
+        let steps = trace(&guide.player_rounds());
+        assert_eq!(
+            steps[1],
+            Step {
+                round: 2,
+                opponent: Move::Paper,
+                player: Move::Rock,
+                outcome: Outcome::Loss,
+                shape_score: 1,
+                outcome_score: 0,
+                total: 5,
+            }
+        );
+        assert_eq!(steps.last().unwrap().total, 12);
+        assert!(to_json_lines(&steps).starts_with(
+            "{\"round\": 1, \"opponent\": \"Rock\", \"player\": \"Rock\", \"outcome\": \"Draw\", \"shape_score\": 1, \"outcome_score\": 3, \"total\": 4}\n"
+        ));
+    }
+}
@@ -0,0 +1,295 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use crate::error::{column_of, AocError};
+use crate::solution::Solution;
+
+/// The example from the puzzle description.
+pub const EXAMPLE: &str = include_str!("../input/day03-example.txt");
+
+/// An item that is not a letter.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+#[error("invalid item {0:?}")]
+pub struct ParseItemError(pub char);
+
+type Compartment = HashSet<char>;
+
+fn compartment_from_str(s: &str) -> Compartment {
+    let mut result: Compartment = HashSet::new();
+    for item in s.chars() {
+        result.insert(item);
+    }
+    result
+}
+
+pub fn priority(item: char) -> Option<i32> {
+    match item {
+        'a'..='z' => Some( 1 + (item as i32) - ('a' as i32)),
+        'A'..='Z' => Some(27 + (item as i32) - ('A' as i32)),
+        _ => None
+    }
+}
+
+/// The item with the given priority.
+fn item(priority: u32) -> char {
+    match priority {
+        1..=26 => (b'a' + (priority - 1) as u8) as char,
+        _ => (b'A' + (priority - 27) as u8) as char,
+    }
+}
+
+/// A set of items, with bit `p` of the mask set for the item of priority `p`.
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    /// Adds `item`, returning false if it is not a letter.
+    pub fn insert(&mut self, item: char) -> bool {
+        let Some(p) = priority(item) else {
+            return false;
+        };
+        self.0 |= 1 << p;
+        true
+    }
+
+    pub fn contains(self, item: char) -> bool {
+        priority(item).is_some_and(|p| self.0 & (1 << p) != 0)
+    }
+
+    pub fn union(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn difference(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & !other.0)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The items by increasing priority.
+    pub fn iter(self) -> Items {
+        Items(self.0)
+    }
+
+    /// The sum of the priorities of the items.
+    pub fn priority(self) -> i32 {
+        self.iter().filter_map(priority).sum()
+    }
+}
+
+pub struct Items(u64);
+
+impl Iterator for Items {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if self.0 == 0 {
+            return None;
+        }
+        let p = self.0.trailing_zeros();
+        self.0 &= self.0 - 1;
+        Some(item(p))
+    }
+}
+
+impl IntoIterator for ItemSet {
+    type Item = char;
+    type IntoIter = Items;
+
+    fn into_iter(self) -> Items {
+        self.iter()
+    }
+}
+
+/// The items of a compartment, or of a whole rucksack.
+impl FromStr for ItemSet {
+    type Err = ParseItemError;
+
+    fn from_str(s: &str) -> Result<Self, ParseItemError> {
+        let mut items = ItemSet::default();
+        match s.chars().find(|&c| !items.insert(c)) {
+            Some(c) => Err(ParseItemError(c)),
+            None => Ok(items),
+        }
+    }
+}
+
+/// Part 1 with a hash set for the first compartment.
+pub fn part1_by_hash_set(rucksacks: &[String]) -> Result<i32, AocError> {
+    rucksacks.iter().enumerate().map(|(i, line)| {
+        let (left, right) = line.split_at(line.len()/2);
+        let left_hash = compartment_from_str(left);
+        let common = right.chars().find(|c| left_hash.contains(c)).ok_or_else(|| {
+            AocError::NoAnswer(format!("rucksack {} has no item in both compartments", i + 1))
+        })?;
+        Ok(priority(common).unwrap_or(0))
+    }).sum()
+}
+
+/// Part 2 intersecting a hash set per rucksack.
+pub fn part2_by_hash_set(rucksacks: &[String]) -> Result<i32, AocError> {
+    if !rucksacks.len().is_multiple_of(3) {
+        return Err(AocError::Invalid("the elves cannot be split in groups of three".into()));
+    }
+    rucksacks.chunks(3).enumerate().map(|(i, g)| {
+        let a: HashSet<char> = HashSet::from_iter(g[0].chars().to_owned());
+        let b: HashSet<char> = HashSet::from_iter(g[1].chars().to_owned());
+        let c: HashSet<char> = HashSet::from_iter(g[2].chars().to_owned());
+        let badge = a.intersection(&b).find(|it: &&char| c.contains(*it)).ok_or_else(|| {
+            AocError::NoAnswer(format!("group {} has no item in all three rucksacks", i + 1))
+        })?;
+        Ok(priority(*badge).unwrap_or(0))
+    }).sum()
+}
+
+/// Part 1 building the item sets from the lines, like [`part1_by_hash_set`].
+pub fn part1_by_item_set(rucksacks: &[String]) -> Result<i32, AocError> {
+    rucksacks.iter().enumerate().map(|(i, line)| shared(i, parse_rucksack(i + 1, line)?)).sum()
+}
+
+/// Part 2 building the item sets from the lines, like [`part2_by_hash_set`].
+pub fn part2_by_item_set(rucksacks: &[String]) -> Result<i32, AocError> {
+    if !rucksacks.len().is_multiple_of(3) {
+        return Err(AocError::Invalid("the elves cannot be split in groups of three".into()));
+    }
+    rucksacks.chunks(3).enumerate().map(|(i, g)| {
+        let mut group = [(ItemSet::default(), ItemSet::default()); 3];
+        for (j, line) in g.iter().enumerate() {
+            group[j] = parse_rucksack(3 * i + j + 1, line)?;
+        }
+        badge(i, &group)
+    }).sum()
+}
+
+/// The two compartments of a rucksack.
+pub type Rucksack = (ItemSet, ItemSet);
+
+/// Reads line number `line` of the input.
+fn parse_rucksack(line: usize, s: &str) -> Result<Rucksack, AocError> {
+    let compartment = |part: &str, offset: usize| {
+        part.parse::<ItemSet>()
+            .map_err(|e| AocError::parse(line, offset + column_of(part, &e.0.to_string()), e))
+    };
+    if !s.is_ascii() || !s.len().is_multiple_of(2) {
+        // An invalid item is reported before the sizes.
+        compartment(s, 0)?;
+        return Err(AocError::Malformed { line, reason: "compartments have different sizes".into() });
+    }
+    let (left, right) = s.split_at(s.len() / 2);
+    Ok((compartment(left, 0)?, compartment(right, left.len())?))
+}
+
+/// Priority of the item in both compartments of rucksack `i`.
+fn shared(i: usize, (left, right): Rucksack) -> Result<i32, AocError> {
+    match left.intersection(right).iter().next() {
+        Some(item) => Ok(priority(item).unwrap_or(0)),
+        None => Err(AocError::NoAnswer(format!("rucksack {} has no item in both compartments", i + 1))),
+    }
+}
+
+/// Priority of the item in all three rucksacks of group `i`.
+fn badge(i: usize, group: &[Rucksack]) -> Result<i32, AocError> {
+    let badge = group
+        .iter()
+        .map(|(left, right)| left.union(*right))
+        .reduce(ItemSet::intersection)
+        .unwrap_or_default();
+    match badge.iter().next() {
+        Some(item) => Ok(priority(item).unwrap_or(0)),
+        None => Err(AocError::NoAnswer(format!("group {} has no item in all three rucksacks", i + 1))),
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Rucksack>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<Rucksack>, AocError> {
+        input.lines().enumerate().map(|(i, line)| parse_rucksack(i + 1, line)).collect()
+    }
+
+    fn part1(rucksacks: &Vec<Rucksack>) -> Result<i32, AocError> {
+        rucksacks.iter().enumerate().map(|(i, &rucksack)| shared(i, rucksack)).sum()
+    }
+
+    fn part2(rucksacks: &Vec<Rucksack>) -> Result<i32, AocError> {
+        if !rucksacks.len().is_multiple_of(3) {
+            return Err(AocError::Invalid("the elves cannot be split in groups of three".into()));
+        }
+        rucksacks.chunks(3).enumerate().map(|(i, group)| badge(i, group)).sum()
+    }
+}
+
+#[test]
+fn test_lowercase_priority() {
+    assert_eq!(priority('a'), Some(1));
+    assert_eq!(priority('b'), Some(2));
+}
+
+#[test]
+fn test_uppercase_priority() {
+    assert_eq!(priority('A'), Some(27));
+    assert_eq!(priority('B'), Some(28));
+}
+
+#[test]
+fn test_item_set() {
+    let left: ItemSet = "vJrwpWtwJgWr".parse().unwrap();
+    let right: ItemSet = "hcsFMMfFFhFp".parse().unwrap();
+    assert_eq!(left.intersection(right).iter().collect::<Vec<_>>(), vec!['p']);
+    assert_eq!(left.len(), 8);
+    assert_eq!(left.union(right).len(), 8 + 7 - 1);
+    assert!(!left.difference(right).contains('p'));
+    assert!(left.difference(right).contains('W'));
+    assert_eq!("zaZA".parse::<ItemSet>().unwrap().iter().collect::<String>(), "azAZ");
+    assert_eq!("aZ".parse::<ItemSet>().unwrap().priority(), 1 + 52);
+    assert_eq!("ab1".parse::<ItemSet>(), Err(ParseItemError('1')));
+}
+
+#[test]
+fn test_item_set_matches_hash_set() {
+    for seed in 0..10 {
+        let input = crate::generate::generate(3, seed, 30).unwrap().input;
+        let rucksacks = Day03::parse(&input).unwrap();
+        let lines: Vec<String> = input.lines().map(String::from).collect();
+        let part1 = Day03::part1(&rucksacks).unwrap();
+        let part2 = Day03::part2(&rucksacks).unwrap();
+        assert_eq!((part1_by_hash_set(&lines).unwrap(), part2_by_hash_set(&lines).unwrap()), (part1, part2));
+        assert_eq!((part1_by_item_set(&lines).unwrap(), part2_by_item_set(&lines).unwrap()), (part1, part2));
+    }
+}
+
+#[test]
+fn test_parse_errors() {
+    assert!(matches!(Day03::parse("abcd\nab1d\n"), Err(AocError::Parse { line: 2, column: 3, .. })));
+    assert!(Day03::parse("abcdXz\n").is_ok());
+    assert!(matches!(Day03::parse("abcde\n"), Err(AocError::Malformed { line: 1, .. })));
+    assert!(matches!(Day03::parse("aéb\n"), Err(AocError::Parse { line: 1, column: 2, .. })));
+}
+
+#[test]
+fn test_group_without_badge() {
+    let rucksacks = Day03::parse("abca\ndefd\nghig\n").unwrap();
+    assert_eq!(Day03::part1(&rucksacks).unwrap(), 1 + 4 + 7);
+    let error = Day03::part2(&rucksacks).unwrap_err();
+    assert!(matches!(error, AocError::NoAnswer(_)));
+    assert!(error.to_string().contains("group 1"));
+    let lines: Vec<String> = ["abca", "defd", "ghig"].map(String::from).to_vec();
+    assert_eq!(part2_by_hash_set(&lines).unwrap_err().to_string(), error.to_string());
+    assert_eq!(part2_by_item_set(&lines).unwrap_err().to_string(), error.to_string());
+}
+
+crate::example_tests!(Day03, example: EXAMPLE => (157, 70));
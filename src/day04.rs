@@ -0,0 +1,103 @@
+use std::num::ParseIntError;
+use std::str::FromStr;
+use std::cmp::{max,min};
+
+use crate::error::AocError;
+use crate::solution::Solution;
+
+/// The example from the puzzle description.
+pub const EXAMPLE: &str = include_str!("../input/day04-example.txt");
+
+pub struct Range {
+    pub lower: u32,
+    pub upper: u32
+}
+
+impl Range {
+    pub fn contains(&self, other: &Range) -> bool {
+        self.lower <= other.lower && other.upper <= self.upper
+    }
+
+    pub fn overlaps(&self, other: &Range) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    pub fn intersection(&self, other: &Range) -> Option<Range> {
+        let lower = max(self.lower, other.lower);
+        let upper = min(self.upper, other.upper);
+        if lower<=upper {
+            Some(Range {lower, upper})
+        } else {
+            None
+        }
+    }
+}
+
+/// Parses a range, giving the byte offset of the bound that failed.
+fn parse_range(s: &str) -> Result<Range, (usize, ParseIntError)> {
+    let (lower, upper) = s.split_once('-').unwrap_or((s, ""));
+    Ok(Range {
+        lower: lower.parse().map_err(|e| (0, e))?,
+        upper: upper.parse().map_err(|e| (lower.len() + 1, e))?,
+    })
+}
+
+impl FromStr for Range {
+    type Err = ParseIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_range(s).map_err(|(_, e)| e)
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<(Range, Range)>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let (first, second) = line.split_once(',').unwrap_or((line, ""));
+            let first_range = parse_range(first).map_err(|(at, e)| AocError::parse(i + 1, at + 1, e))?;
+            let second_range = parse_range(second)
+                .map_err(|(at, e)| AocError::parse(i + 1, first.len() + 2 + at, e))?;
+            Ok((first_range, second_range))
+        })
+        .collect()
+    }
+
+    fn part1(pairs: &Self::Input) -> Result<usize, AocError> {
+        Ok(pairs
+        .iter()
+        .filter(|(first_range, second_range)| {
+            first_range.contains(second_range) || second_range.contains(first_range)
+        })
+        .count())
+    }
+
+    fn part2(pairs: &Self::Input) -> Result<usize, AocError> {
+        Ok(pairs
+        .iter()
+        .filter(|(first_range, second_range)| first_range.overlaps(second_range))
+        .count())
+    }
+}
+
+#[test]
+fn test_error_columns() {
+    let column = |input: &str| match Day04::parse(input) {
+        Err(AocError::Parse { column, .. }) => column,
+        _ => panic!("expected a parse error for {:?}", input),
+    };
+    assert_eq!(column("x-4,6-8"), 1);
+    assert_eq!(column("2-x,6-8"), 3);
+    assert_eq!(column("2-4,x-8"), 5);
+    assert_eq!(column("2-4,16-x"), 8);
+    assert_eq!(column("2-4"), 5);
+}
+
+crate::example_tests!(Day04, example: EXAMPLE => (2, 4));
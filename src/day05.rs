@@ -0,0 +1,284 @@
+use std::str::FromStr;
+
+use crate::error::AocError;
+use crate::solution::Solution;
+
+/// The example from the puzzle description.
+pub const EXAMPLE: &str = include_str!("../input/day05-example.txt");
+
+pub type Stack = Vec<char>;
+
+pub fn parse_to_crates(drawing: &str) -> Vec<Stack> {
+    let mut drawing_rev = drawing.lines().rev();
+    let length = drawing_rev.next().unwrap_or_default().split_whitespace().count();
+    let mut crates: Vec<Stack> = Vec::with_capacity(length);
+    crates.resize_with(length, Vec::new);
+
+    for line in drawing_rev {
+        for i in 0..length {
+            let chars: Vec<char> = line.chars().collect();
+            if let Some(cargo_crate) = chars.get(4 * i + 1) {
+                if 'A' <= *cargo_crate && *cargo_crate <= 'Z' {
+                    let stack: &mut Stack = crates.get_mut(i).unwrap();
+                    stack.push(cargo_crate.to_owned());
+                }
+            }
+        }
+    }
+    crates
+}
+
+#[derive(Debug)]
+pub struct Action {
+    pub quantity: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ParseActionError {
+    #[error("From not found")]
+    From,
+    #[error("To not found")]
+    To,
+    #[error("Move not found")]
+    Move,
+    #[error("Stacks are numbered from 1")]
+    Stack,
+    #[error("Integer parsing error: {0}")]
+    Int(std::num::ParseIntError),
+}
+
+impl From<std::num::ParseIntError> for ParseActionError {
+    fn from(value: std::num::ParseIntError) -> Self {
+        ParseActionError::Int(value)
+    }
+}
+
+pub fn apply(action: &Action, supplies: &Vec<Stack>) -> Option<Vec<Stack>> {
+    let mut from = supplies.get(action.from)?.to_owned();
+    let mut to = supplies.get(action.to)?.to_owned();
+
+    let mut result = supplies.to_owned();
+    match action.quantity {
+        n if n <= from.len() => {
+            for _ in 0..action.quantity {
+                to.push(from.pop()?)
+            }
+            result[action.from] = from;
+            result[action.to] = to;
+            Some(result)
+        }
+        _ => None,
+    }
+}
+
+pub fn apply_at_once(action: &Action, supplies: &Vec<Stack>) -> Option<Vec<Stack>> {
+    let mut from = supplies.get(action.from)?.to_owned();
+    let mut to = supplies.get(action.to)?.to_owned();
+
+    let mut result = supplies.to_owned();
+    match action.quantity {
+        n if n <= from.len() => {
+            let mut grabbed = from.split_off(from.len() - action.quantity);
+            to.append(&mut grabbed);
+            result[action.from] = from;
+            result[action.to] = to;
+            Some(result)
+        }
+        _ => None,
+    }
+}
+
+/// Parses an action, giving the byte offset of the token that failed: the
+/// offending number, the start of the line for a missing `move`, or the end
+/// of the line for a missing `from` or `to`.
+fn parse_action(s: &str) -> Result<Action, (usize, ParseActionError)> {
+    let from_at = s.find("from").ok_or((s.len(), ParseActionError::From))?;
+    let (quantity_spec, direction) = (&s[..from_at], &s[from_at + 4..]);
+    let to_at = direction.find("to").ok_or((s.len(), ParseActionError::To))?;
+    let space = quantity_spec.find(' ').ok_or((0, ParseActionError::Move))?;
+
+    // Each number with the offset of its first digit.
+    let number = |field: &str, offset: usize| {
+        let offset = offset + field.len() - field.trim_start().len();
+        field.trim().parse::<usize>().map(|n| (n, offset)).map_err(|e| (offset, e.into()))
+    };
+    let stack = |(n, offset): (usize, usize)| n.checked_sub(1).ok_or((offset, ParseActionError::Stack));
+    let (quantity, _) = number(&quantity_spec[space + 1..], space + 1)?;
+    let from = number(&direction[..to_at], from_at + 4)?;
+    let to = number(&direction[to_at + 2..], from_at + 4 + to_at + 2)?;
+    Ok(Action { quantity, from: stack(from)?, to: stack(to)? })
+}
+
+impl FromStr for Action {
+    type Err = ParseActionError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_action(s).map_err(|(_, e)| e)
+    }
+}
+
+fn rearrange(
+    crates: &[Stack],
+    actions: &[Action],
+    crane: fn(&Action, &Vec<Stack>) -> Option<Vec<Stack>>,
+) -> Result<String, AocError> {
+    let final_state: Vec<Stack> = actions.iter().enumerate().try_fold(crates.to_vec(), |acc, (i, e)| {
+        crane(e, &acc).ok_or_else(|| {
+            AocError::Invalid(format!(
+                "move {} from {} to {} (action {}) cannot be applied to {:?}",
+                e.quantity,
+                e.from + 1,
+                e.to + 1,
+                i + 1,
+                acc
+            ))
+        })
+    })?;
+
+    final_state
+        .iter()
+        .enumerate()
+        .map(|(i, stack)| {
+            stack
+                .last()
+                .ok_or_else(|| AocError::NoAnswer(format!("stack {} is empty", i + 1)))
+        })
+        .collect()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Vec<Stack>, Vec<Action>);
+    type Output1 = String;
+    type Output2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let (initial_state, actions_spec) = input
+            .split_once("\n\n")
+            .ok_or_else(|| AocError::Malformed {
+                line: input.lines().count() + 1,
+                reason: "expected a blank line between the drawing and the actions".into(),
+            })?;
+        let crates: Vec<Stack> = parse_to_crates(initial_state);
+        let first_action_line = initial_state.lines().count() + 2;
+
+        let actions: Vec<Action> = actions_spec
+            .lines()
+            .enumerate()
+            .map(|(i, line)| parse_action(line).map_err(|(at, e)| AocError::parse(first_action_line + i, at + 1, e)))
+            .collect::<Result<_, _>>()?;
+
+        Ok((crates, actions))
+    }
+
+    fn part1((crates, actions): &Self::Input) -> Result<String, AocError> {
+        rearrange(crates, actions, apply)
+    }
+
+    fn part2((crates, actions): &Self::Input) -> Result<String, AocError> {
+        rearrange(crates, actions, apply_at_once)
+    }
+}
+
+#[test]
+fn test_initial_state() {
+    let initial_state: Vec<Stack> = vec![
+        vec!['F', 'C', 'P', 'G', 'Q', 'R'],
+        vec!['W', 'T', 'C', 'P'],
+        vec!['B', 'H', 'P', 'M', 'C'],
+        vec!['L', 'T', 'Q', 'S', 'M', 'P', 'R'],
+        vec!['P', 'H', 'J', 'Z', 'V', 'G', 'N'],
+        vec!['D', 'P', 'J'],
+        vec!['L', 'G', 'P', 'Z', 'F', 'J', 'T', 'R'],
+        vec!['N', 'L', 'H', 'C', 'F', 'P', 'T', 'J'],
+        vec!['G', 'V', 'Z', 'Q', 'H', 'T', 'C', 'W'],
+    ];
+    let initial_state_spec = concat!(
+        "                        [R] [J] [W]\n",
+        "            [R] [N]     [T] [T] [C]\n",
+        "[R]         [P] [G]     [J] [P] [T]\n",
+        "[Q]     [C] [M] [V]     [F] [F] [H]\n",
+        "[G] [P] [M] [S] [Z]     [Z] [C] [Q]\n",
+        "[P] [C] [P] [Q] [J] [J] [P] [H] [Z]\n",
+        "[C] [T] [H] [T] [H] [P] [G] [L] [V]\n",
+        "[F] [W] [B] [L] [P] [D] [L] [N] [G]\n",
+        " 1   2   3   4   5   6   7   8   9 ");
+    let crates: Vec<Stack> = parse_to_crates(initial_state_spec);
+    assert_eq!(initial_state, crates);
+}
+
+#[test]
+fn test_first_move() {
+    let initial_state: Vec<Stack> = vec![
+        vec!['F', 'C', 'P', 'G', 'Q', 'R'],
+        vec!['W', 'T', 'C', 'P'],
+        vec!['B', 'H', 'P', 'M', 'C'],
+        vec!['L', 'T', 'Q', 'S', 'M', 'P', 'R'],
+        vec!['P', 'H', 'J', 'Z', 'V', 'G', 'N'],
+        vec!['D', 'P', 'J'],
+        vec!['L', 'G', 'P', 'Z', 'F', 'J', 'T', 'R'],
+        vec!['N', 'L', 'H', 'C', 'F', 'P', 'T', 'J'],
+        vec!['G', 'V', 'Z', 'Q', 'H', 'T', 'C', 'W'],
+    ];
+
+    let first_move = Action { quantity: 2, from: 1, to: 7 };
+    
+    let final_state: Vec<Stack> = vec![
+        vec!['F', 'C', 'P', 'G', 'Q', 'R'],
+        vec!['W', 'T'],
+        vec!['B', 'H', 'P', 'M', 'C'],
+        vec!['L', 'T', 'Q', 'S', 'M', 'P', 'R'],
+        vec!['P', 'H', 'J', 'Z', 'V', 'G', 'N'],
+        vec!['D', 'P', 'J'],
+        vec!['L', 'G', 'P', 'Z', 'F', 'J', 'T', 'R'],
+        vec!['N', 'L', 'H', 'C', 'F', 'P', 'T', 'J', 'P', 'C'],
+        vec!['G', 'V', 'Z', 'Q', 'H', 'T', 'C', 'W'],
+    ];
+    assert_eq!(final_state, apply(&first_move, &initial_state).unwrap());
+}
+
+#[test]
+fn test_first_move_at_once() {
+    let initial_state: Vec<Stack> = vec![
+        vec!['F', 'C', 'P', 'G', 'Q', 'R'],
+        vec!['W', 'T', 'C', 'P'],
+        vec!['B', 'H', 'P', 'M', 'C'],
+        vec!['L', 'T', 'Q', 'S', 'M', 'P', 'R'],
+        vec!['P', 'H', 'J', 'Z', 'V', 'G', 'N'],
+        vec!['D', 'P', 'J'],
+        vec!['L', 'G', 'P', 'Z', 'F', 'J', 'T', 'R'],
+        vec!['N', 'L', 'H', 'C', 'F', 'P', 'T', 'J'],
+        vec!['G', 'V', 'Z', 'Q', 'H', 'T', 'C', 'W'],
+    ];
+
+    let first_move = Action { quantity: 2, from: 1, to: 7 };
+    
+    let final_state: Vec<Stack> = vec![
+        vec!['F', 'C', 'P', 'G', 'Q', 'R'],
+        vec!['W', 'T'],
+        vec!['B', 'H', 'P', 'M', 'C'],
+        vec!['L', 'T', 'Q', 'S', 'M', 'P', 'R'],
+        vec!['P', 'H', 'J', 'Z', 'V', 'G', 'N'],
+        vec!['D', 'P', 'J'],
+        vec!['L', 'G', 'P', 'Z', 'F', 'J', 'T', 'R'],
+        vec!['N', 'L', 'H', 'C', 'F', 'P', 'T', 'J', 'C', 'P'],
+        vec!['G', 'V', 'Z', 'Q', 'H', 'T', 'C', 'W'],
+    ];
+    assert_eq!(final_state, apply_at_once(&first_move, &initial_state).unwrap());
+}
+
+#[test]
+fn test_action_error_columns() {
+    let column = |line: &str| parse_action(line).map(|_| ()).unwrap_err().0 + 1;
+    assert_eq!(column("move x from 2 to 1"), 6);
+    assert_eq!(column("move 1 from  y to 1"), 14);
+    assert_eq!(column("move 1 from 2 to 0"), 18);
+    assert_eq!(column("move 1 from 2"), 14);
+    assert_eq!(column("movefrom 2 to 1"), 1);
+    let input = EXAMPLE.replace("move 3 from 1 to 3", "move 3 from 1 to z");
+    assert!(matches!(Day05::parse(&input), Err(AocError::Parse { line: 7, column: 18, .. })));
+}
+
+crate::example_tests!(Day05, example: EXAMPLE => ("CMZ", "MCD"));
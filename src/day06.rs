@@ -0,0 +1,40 @@
+use crate::error::AocError;
+use crate::solution::Solution;
+
+/// The first example from the puzzle description.
+pub const EXAMPLE: &str = include_str!("../input/day06-example.txt");
+
+pub fn find_marker(input_chars: &[char], window_size: usize) -> Option<usize> {
+    input_chars
+        .windows(window_size)
+        .position(|s| (0..window_size-1).all(|i| (i + 1..window_size).all(|j| s[i] != s[j])))
+        .map(|position| position + window_size)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<char>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<char>, AocError> {
+        Ok(input.chars().collect())
+    }
+
+    fn part1(input_chars: &Vec<char>) -> Result<usize, AocError> {
+        find_marker(input_chars, 4).ok_or_else(|| AocError::NoAnswer("no start-of-packet marker".into()))
+    }
+
+    fn part2(input_chars: &Vec<char>) -> Result<usize, AocError> {
+        find_marker(input_chars, 14).ok_or_else(|| AocError::NoAnswer("no start-of-message marker".into()))
+    }
+}
+
+crate::example_tests!(Day06,
+    example: EXAMPLE => (7, 19),
+    second_example: "bvwbjplbgvbhsrlpgdmjqwftvncz" => (5, 23),
+    third_example: "nppdvjthqldpwncqszvftbrmjlhg" => (6, 23),
+    fourth_example: "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg" => (10, 29),
+    fifth_example: "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw" => (11, 26),
+);
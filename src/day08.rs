@@ -0,0 +1,239 @@
+use crate::error::AocError;
+use crate::solution::Solution;
+
+/// The example from the puzzle description.
+pub const EXAMPLE: &str = include_str!("../input/day08-example.txt");
+
+pub type Forest = Vec<Vec<u8>>;
+
+pub mod part1 {
+    use super::Forest;
+    fn visible_from_left(forest: &Forest, i: usize, j: usize) -> bool {
+        let line = forest.get(i).unwrap().get(0..j).unwrap_or_default();
+        let this = forest.get(i).unwrap().get(j).unwrap();
+        match line.iter().max() {
+            None => true,
+            Some(max) => max < this,
+        }
+    }
+
+    fn visible_from_right(forest: &Forest, i: usize, j: usize) -> bool {
+        let line = forest
+            .get(i)
+            .unwrap()
+            .get(j + 1..forest.get(i).unwrap().len())
+            .unwrap_or_default();
+        let this = forest.get(i).unwrap().get(j).unwrap();
+        match line.iter().max() {
+            None => true,
+            Some(max) => max < this,
+        }
+    }
+
+    fn visible_from_top(forest: &Forest, i: usize, j: usize) -> bool {
+        let line = forest
+            .get(0..i)
+            .unwrap_or_default()
+            .iter()
+            .map(|l| l.get(j).unwrap());
+        let this = forest.get(i).unwrap().get(j).unwrap();
+        match line.into_iter().max() {
+            None => true,
+            Some(max) => max < this,
+        }
+    }
+
+    fn visible_from_bottom(forest: &Forest, i: usize, j: usize) -> bool {
+        let line = forest
+            .get(i + 1..forest.len())
+            .unwrap_or_default()
+            .iter()
+            .map(|l| l.get(j).unwrap());
+        let this = forest.get(i).unwrap().get(j).unwrap();
+        match line.into_iter().max() {
+            None => true,
+            Some(max) => max < this,
+        }
+    }
+
+    pub fn visible(forest: &Forest, i: usize, j: usize) -> bool {
+        vec![
+            visible_from_left,
+            visible_from_right,
+            visible_from_top,
+            visible_from_bottom,
+        ]
+        .into_iter()
+        .any(|f| f(forest, i, j))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::super::parse_forest;
+        use super::*;
+
+        #[test]
+        fn test_visible_from_top_0() {
+            let input_str = "30373\n\
+        25512\n\
+        65332\n\
+        33549\n\
+        35390";
+            let forest = parse_forest(input_str).unwrap();
+            assert!(
+                visible_from_top(&forest, 0, 0),
+                "(0,0) is not visible from top"
+            );
+            assert!(
+                visible_from_top(&forest, 0, forest.len() - 1),
+                "(0,n) is not visible from top"
+            );
+        }
+    }
+}
+
+pub mod part2 {
+    use super::Forest;
+    fn visibility_left(forest: &Forest, i: usize, j: usize) -> usize {
+        let mut result: usize = 0;
+        for jx in (0..j).rev() {
+            result += 1;
+            if forest[i][jx] >= forest[i][j] {
+                break;
+            }
+        }
+        result
+    }
+
+    fn visibility_right(forest: &Forest, i: usize, j: usize) -> usize {
+        let mut result: usize = 0;
+        for jx in j + 1..forest[i].len() {
+            result += 1;
+            if forest[i][jx] >= forest[i][j] {
+                break;
+            }
+        }
+        result
+    }
+
+    fn visibility_top(forest: &Forest, i: usize, j: usize) -> usize {
+        let mut result: usize = 0;
+        for ix in (0..i).rev() {
+            result += 1;
+            if forest[ix][j] >= forest[i][j] {
+                break;
+            }
+        }
+        result
+    }
+
+    fn visibility_bottom(forest: &Forest, i: usize, j: usize) -> usize {
+        let mut result: usize = 0;
+        for ix in i + 1..forest.len() {
+            result += 1;
+            if forest[ix][j] >= forest[i][j] {
+                break;
+            }
+        }
+        result
+    }
+
+    pub fn scenic_score(forest: &Forest, i: usize, j: usize) -> usize {
+        vec![
+            visibility_left,
+            visibility_right,
+            visibility_top,
+            visibility_bottom,
+        ]
+        .into_iter()
+        .map(|f| f(forest, i, j))
+        .product()
+    }
+}
+
+pub fn parse_forest(value: &str) -> Result<Forest, AocError> {
+    let forest: Forest = value
+        .lines()
+        .enumerate()
+        .map(|(i, l)| {
+            l.chars()
+                .enumerate()
+                .map(|(j, c)| c.to_string().parse().map_err(|e| AocError::parse(i + 1, j + 1, e)))
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+    if let Some(width) = forest.first().map(|row| row.len()) {
+        if let Some(i) = forest.iter().position(|row| row.len() != width) {
+            return Err(AocError::Malformed {
+                line: i + 1,
+                reason: format!("expected {} trees, found {}", width, forest[i].len()),
+            });
+        }
+    }
+    Ok(forest)
+}
+
+// half naïve solution: iterate over rows and count the
+pub fn part1_by_row_count(input_forest: &Forest) -> usize {
+    input_forest
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|(j, _)| part1::visible(input_forest, i, *j))
+                .count()
+        })
+        .sum()
+}
+
+/*
+To do the nested iteration, we need to be careful about borrowing.
+If we do it the naïve way, with just nested map, we get the following error:
+    closure may outlive the current function, but it borrows `i`, which is owned by the current function
+To fix this, we change the closure to a `move` closure.
+The `move` keyword will move all variables mentioned in the scope, including `input_forest`,
+but since we only hold a reference to the forest, only the reference is moved.
+*/
+pub fn part1_by_nested_map(input_forest: &Forest) -> usize {
+    input_forest
+        .iter()
+        .enumerate()
+        .flat_map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .filter(move |(j, _)| part1::visible(input_forest, i, *j))
+        })
+        .count()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Forest;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Forest, AocError> {
+        parse_forest(input)
+    }
+
+    fn part1(input_forest: &Forest) -> Result<usize, AocError> {
+        Ok(part1_by_row_count(input_forest))
+    }
+
+    fn part2(input_forest: &Forest) -> Result<usize, AocError> {
+        input_forest
+            .iter()
+            .enumerate()
+            .flat_map(|(i, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(move |(j, _)| part2::scenic_score(input_forest, i, j))
+            })
+            .max()
+            .ok_or_else(|| AocError::NoAnswer("the forest is empty".into()))
+    }
+}
+
+crate::example_tests!(Day08, example: EXAMPLE => (21, 8));
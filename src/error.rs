@@ -0,0 +1,122 @@
+use std::num::ParseIntError;
+
+use crate::{day01, day02, day03, day05, day07};
+
+/// The per-day parse errors, so they can be reported with their position.
+#[derive(Debug, thiserror::Error)]
+pub enum ParseError {
+    #[error("{0}")]
+    Int(ParseIntError),
+    #[error("{0}")]
+    Food(day01::ParseFoodError),
+    #[error("{0}")]
+    Round(day02::round::ParseError),
+    #[error("{0}")]
+    Item(day03::ParseItemError),
+    #[error("{0}")]
+    Action(day05::ParseActionError),
+    #[error("{0}")]
+    Command(day07::ParseCommandError),
+    #[error("{0}")]
+    File(day07::ParseFileError),
+}
+
+impl From<ParseIntError> for ParseError {
+    fn from(value: ParseIntError) -> Self {
+        ParseError::Int(value)
+    }
+}
+
+impl From<day01::ParseFoodError> for ParseError {
+    fn from(value: day01::ParseFoodError) -> Self {
+        ParseError::Food(value)
+    }
+}
+
+impl From<day02::round::ParseError> for ParseError {
+    fn from(value: day02::round::ParseError) -> Self {
+        ParseError::Round(value)
+    }
+}
+
+impl From<day03::ParseItemError> for ParseError {
+    fn from(value: day03::ParseItemError) -> Self {
+        ParseError::Item(value)
+    }
+}
+
+impl From<day05::ParseActionError> for ParseError {
+    fn from(value: day05::ParseActionError) -> Self {
+        ParseError::Action(value)
+    }
+}
+
+impl From<day07::ParseCommandError> for ParseError {
+    fn from(value: day07::ParseCommandError) -> Self {
+        ParseError::Command(value)
+    }
+}
+
+impl From<day07::ParseFileError> for ParseError {
+    fn from(value: day07::ParseFileError) -> Self {
+        ParseError::File(value)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AocError {
+    #[error("cannot read {path}: {source}")]
+    Io {
+        path: String,
+        source: std::io::Error,
+    },
+    #[error("line {line}, column {column}: {source}")]
+    Parse {
+        line: usize,
+        column: usize,
+        source: ParseError,
+    },
+    #[error("line {line}: {reason}")]
+    Malformed { line: usize, reason: String },
+    #[error("{0}")]
+    Invalid(String),
+    #[error("no answer: {0}")]
+    NoAnswer(String),
+    /// Every error found in one pass over the input, in order.
+    #[error("{} errors\n{}", .0.len(), list(.0))]
+    Many(Vec<AocError>),
+}
+
+fn list(errors: &[AocError]) -> String {
+    let lines: Vec<String> = errors.iter().map(|e| format!("  {}", e)).collect();
+    lines.join("\n")
+}
+
+impl AocError {
+    /// Builds a parse error; `line` and `column` are 1-based.
+    pub fn parse(line: usize, column: usize, source: impl Into<ParseError>) -> Self {
+        AocError::Parse {
+            line,
+            column,
+            source: source.into(),
+        }
+    }
+
+    /// The values if there are no errors, or else the error, or all of them.
+    pub fn collect<T>(values: Vec<T>, mut errors: Vec<AocError>) -> Result<Vec<T>, AocError> {
+        match errors.len() {
+            0 => Ok(values),
+            1 => Err(errors.remove(0)),
+            _ => Err(AocError::Many(errors)),
+        }
+    }
+}
+
+/// 1-based column of `token` within `line`, or the end of the line if the
+/// token is missing.
+pub fn column_of(line: &str, token: &str) -> usize {
+    match token {
+        "" => line.len() + 1,
+        _ => line.find(token).map_or(1, |i| i + 1),
+    }
+}
@@ -0,0 +1,185 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::runner::Part;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Format {
+    /// Human-readable table.
+    Plain,
+    /// One JSON object per line.
+    Json,
+    /// Test Anything Protocol.
+    Tap,
+}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "plain" => Ok(Format::Plain),
+            "json" => Ok(Format::Json),
+            "tap" => Ok(Format::Tap),
+            _ => Err(s.to_string()),
+        }
+    }
+}
+
+/// The result of solving one part, or the error that prevented it.
+#[derive(Debug)]
+pub struct Outcome {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Result<String, String>,
+    pub parse_time: Duration,
+    pub elapsed: Duration,
+}
+
+pub fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+pub fn render(format: Format, outcomes: &[Outcome]) -> String {
+    match format {
+        Format::Plain => plain(outcomes),
+        Format::Json => json_lines(outcomes),
+        Format::Tap => tap(outcomes),
+    }
+}
+
+fn plain(outcomes: &[Outcome]) -> String {
+    let width = outcomes
+        .iter()
+        .map(|o| o.answer.as_ref().map_or(5, String::len))
+        .max()
+        .unwrap_or(0)
+        .max(6);
+    let mut out = format!(
+        "{:>3}  {:>4}  {:<width$}  {:>10}  {:>10}\n",
+        "Day", "Part", "Answer", "Parse", "Solve"
+    );
+    for o in outcomes {
+        let answer = o.answer.as_deref().unwrap_or("ERROR");
+        writeln!(
+            out,
+            "{:>3}  {:>4}  {:<width$}  {:>10}  {:>10}",
+            o.day,
+            o.part,
+            answer,
+            format!("{:.1?}", o.parse_time),
+            format!("{:.1?}", o.elapsed)
+        )
+        .unwrap();
+    }
+    for o in outcomes {
+        if let Err(e) = &o.answer {
+            writeln!(out, "day {} part {}: {}", o.day, o.part, e).unwrap();
+        }
+    }
+    out
+}
+
+fn json_lines(outcomes: &[Outcome]) -> String {
+    let mut out = String::new();
+    for o in outcomes {
+        let result = match &o.answer {
+            Ok(answer) => format!("\"answer\": {}", json_string(answer)),
+            Err(e) => format!("\"error\": {}", json_string(e)),
+        };
+        writeln!(
+            out,
+            "{{\"day\": {}, \"part\": {}, {}, \"parse_ns\": {}, \"duration_ns\": {}}}",
+            o.day,
+            o.part,
+            result,
+            o.parse_time.as_nanos(),
+            o.elapsed.as_nanos()
+        )
+        .unwrap();
+    }
+    out
+}
+
+fn tap(outcomes: &[Outcome]) -> String {
+    let mut out = format!("TAP version 13\n1..{}\n", outcomes.len());
+    for (i, o) in outcomes.iter().enumerate() {
+        match &o.answer {
+            Ok(answer) => writeln!(
+                out,
+                "ok {} - day {} part {}: {} # time={:.3}ms",
+                i + 1,
+                o.day,
+                o.part,
+                answer,
+                o.elapsed.as_secs_f64() * 1000.0
+            ),
+            Err(e) => writeln!(
+                out,
+                "not ok {} - day {} part {}\n  ---\n  message: {}\n  ...",
+                i + 1,
+                o.day,
+                o.part,
+                json_string(e)
+            ),
+        }
+        .unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcomes() -> Vec<Outcome> {
+        vec![
+            Outcome {
+                day: 5,
+                part: Part::One,
+                answer: Ok("CMZ".into()),
+                parse_time: Duration::from_micros(3),
+                elapsed: Duration::from_micros(2),
+            },
+            Outcome {
+                day: 5,
+                part: Part::Two,
+                answer: Err("stack 2 is \"empty\"".into()),
+                parse_time: Duration::from_micros(3),
+                elapsed: Duration::ZERO,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_json_lines() {
+        assert_eq!(
+            render(Format::Json, &outcomes()),
+            "{\"day\": 5, \"part\": 1, \"answer\": \"CMZ\", \"parse_ns\": 3000, \"duration_ns\": 2000}\n\
+             {\"day\": 5, \"part\": 2, \"error\": \"stack 2 is \\\"empty\\\"\", \"parse_ns\": 3000, \"duration_ns\": 0}\n"
+        );
+    }
+
+    #[test]
+    fn test_tap() {
+        let tap = render(Format::Tap, &outcomes());
+        let lines: Vec<&str> = tap.lines().collect();
+        assert_eq!(lines[0], "TAP version 13");
+        assert_eq!(lines[1], "1..2");
+        assert!(lines[2].starts_with("ok 1 - day 5 part 1: CMZ"));
+        assert_eq!(lines[3], "not ok 2 - day 5 part 2");
+    }
+}
@@ -0,0 +1,410 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use crate::day01::{Elf, Food, Inventory};
+
+/// Small deterministic PRNG (SplitMix64), so that generated inputs can be
+/// reproduced from their seed.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `0..n`; `n` must not be zero.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// Uniform in `lo..=hi`.
+    pub fn range(&mut self, lo: usize, hi: usize) -> usize {
+        lo + self.below(hi - lo + 1)
+    }
+
+    pub fn choose<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// A generated input together with its expected answers.
+///
+/// The answers are worked out from the generator's own model of the input,
+/// not by running the solvers.
+#[derive(Debug)]
+pub struct Generated {
+    pub input: String,
+    pub part1: String,
+    pub part2: String,
+}
+
+pub struct Generator {
+    pub day: u8,
+    /// What the size parameter controls.
+    pub size: &'static str,
+    pub generate: fn(&mut Rng, usize) -> Generated,
+}
+
+pub const GENERATORS: [Generator; 8] = [
+    Generator { day: 1, size: "elves", generate: day01 },
+    Generator { day: 2, size: "rounds", generate: day02 },
+    Generator { day: 3, size: "groups of three rucksacks", generate: day03 },
+    Generator { day: 4, size: "pairs", generate: day04 },
+    Generator { day: 5, size: "moves", generate: day05 },
+    Generator { day: 6, size: "characters (roughly)", generate: day06 },
+    Generator { day: 7, size: "directories", generate: day07 },
+    Generator { day: 8, size: "rows and columns", generate: day08 },
+];
+
+pub fn find(day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|g| g.day == day)
+}
+
+/// Generates an input for `day`, or `None` if there is no generator for it.
+pub fn generate(day: u8, seed: u64, size: usize) -> Option<Generated> {
+    find(day).map(|g| (g.generate)(&mut Rng::new(seed), size))
+}
+
+const LETTERS: &[u8; 26] = b"abcdefghijklmnopqrstuvwxyz";
+const ITEMS: &[u8; 52] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Calorie blocks; there are always at least three elves.
+fn day01(rng: &mut Rng, size: usize) -> Generated {
+    let mut elves = vec![];
+    let mut totals = vec![];
+    for _ in 0..size.max(3) {
+        let items: Vec<usize> = (0..rng.range(1, 10)).map(|_| rng.range(1_000, 69_999)).collect();
+        totals.push(items.iter().sum::<usize>());
+        elves.push(Elf { foods: items.iter().map(|&c| Food { calories: c as u64 }).collect() });
+    }
+    totals.sort_unstable_by(|a, b| b.cmp(a));
+    Generated {
+        input: Inventory { elves }.to_string(),
+        part1: totals[0].to_string(),
+        part2: totals[..3].iter().sum::<usize>().to_string(),
+    }
+}
+
+/// Strategy guide lines, `A`-`C` followed by `X`-`Z`.
+fn day02(rng: &mut Rng, size: usize) -> Generated {
+    let mut input = String::new();
+    let (mut part1, mut part2) = (0, 0);
+    for _ in 0..size {
+        let (opponent, second) = (rng.below(3), rng.below(3));
+        writeln!(input, "{} {}", (b'A' + opponent as u8) as char, (b'X' + second as u8) as char).unwrap();
+        // Read as a move: 0 = rock, 1 = paper, 2 = scissors, and each move
+        // beats the one before it.
+        part1 += second + 1 + 3 * ((second + 4 - opponent) % 3);
+        // Read as an outcome: 0 = lose, 1 = draw, 2 = win.
+        part2 += (opponent + second + 2) % 3 + 1 + 3 * second;
+    }
+    Generated {
+        input,
+        part1: part1.to_string(),
+        part2: part2.to_string(),
+    }
+}
+
+fn item_priority(item: u8) -> usize {
+    ITEMS.iter().position(|&i| i == item).unwrap() + 1
+}
+
+/// `half` items made of `required` and random picks from `pool`.
+fn fill_compartment(rng: &mut Rng, required: &[u8], pool: &[u8], half: usize) -> Vec<u8> {
+    let mut compartment = required.to_vec();
+    compartment.extend((required.len()..half).map(|_| rng.choose(pool)));
+    rng.shuffle(&mut compartment);
+    compartment
+}
+
+/// Rucksacks in groups of three. Each rucksack has exactly one item in both
+/// compartments and each group has exactly one item in all three rucksacks.
+fn day03(rng: &mut Rng, size: usize) -> Generated {
+    let mut input = String::new();
+    let (mut part1, mut part2) = (0, 0);
+    for _ in 0..size {
+        let mut items = ITEMS.to_vec();
+        rng.shuffle(&mut items);
+        let badge = items[0];
+        part2 += item_priority(badge);
+        // The remaining items are split in three disjoint pools, one per
+        // rucksack, so that only the badge is shared by the whole group.
+        for (&common, pool) in items[1..4].iter().zip(items[4..].chunks(16)) {
+            part1 += item_priority(common);
+            let (left_pool, right_pool) = pool.split_at(8);
+            let half = rng.range(2, 16);
+            let (with_badge, without_badge) = ([common, badge], [common]);
+            let (left, right): (&[u8], &[u8]) = match rng.below(2) {
+                0 => (&with_badge, &without_badge),
+                _ => (&without_badge, &with_badge),
+            };
+            let left = fill_compartment(rng, left, left_pool, half);
+            let right = fill_compartment(rng, right, right_pool, half);
+            input.push_str(std::str::from_utf8(&left).unwrap());
+            input.push_str(std::str::from_utf8(&right).unwrap());
+            input.push('\n');
+        }
+    }
+    Generated {
+        input,
+        part1: part1.to_string(),
+        part2: part2.to_string(),
+    }
+}
+
+/// Pairs of section ranges within `1..=99`.
+fn day04(rng: &mut Rng, size: usize) -> Generated {
+    let mut input = String::new();
+    let (mut part1, mut part2) = (0, 0);
+    for _ in 0..size {
+        let a = rng.range(1, 99);
+        let b = rng.range(a, 99);
+        let c = rng.range(1, 99);
+        let d = rng.range(c, 99);
+        writeln!(input, "{}-{},{}-{}", a, b, c, d).unwrap();
+        if (a <= c && d <= b) || (c <= a && b <= d) {
+            part1 += 1;
+        }
+        if a <= d && c <= b {
+            part2 += 1;
+        }
+    }
+    Generated {
+        input,
+        part1: part1.to_string(),
+        part2: part2.to_string(),
+    }
+}
+
+/// A drawing of two to nine stacks and a list of moves. Moves never take the
+/// last crate of a stack, so every stack has a top crate at the end.
+fn day05(rng: &mut Rng, size: usize) -> Generated {
+    let count = rng.range(2, 9);
+    let stacks: Vec<Vec<char>> = (0..count)
+        .map(|_| (0..rng.range(2, 8)).map(|_| rng.choose(&ITEMS[26..]) as char).collect())
+        .collect();
+
+    let height = stacks.iter().map(Vec::len).max().unwrap();
+    let mut input = String::new();
+    for level in (0..height).rev() {
+        let row: Vec<String> = stacks
+            .iter()
+            .map(|s| s.get(level).map_or("   ".to_string(), |c| format!("[{}]", c)))
+            .collect();
+        writeln!(input, "{}", row.join(" ")).unwrap();
+    }
+    let labels: Vec<String> = (1..=count).map(|i| format!(" {} ", i)).collect();
+    writeln!(input, "{}\n", labels.join(" ")).unwrap();
+
+    let (mut one_by_one, mut at_once) = (stacks.clone(), stacks);
+    for _ in 0..size {
+        let sources: Vec<usize> = (0..count).filter(|&i| one_by_one[i].len() >= 2).collect();
+        if sources.is_empty() {
+            break;
+        }
+        let from = rng.choose(&sources);
+        let to = (from + rng.range(1, count - 1)) % count;
+        let quantity = rng.range(1, one_by_one[from].len() - 1);
+        writeln!(input, "move {} from {} to {}", quantity, from + 1, to + 1).unwrap();
+
+        let split = one_by_one[from].len() - quantity;
+        let moved: Vec<char> = one_by_one[from].drain(split..).rev().collect();
+        one_by_one[to].extend(moved);
+        let moved: Vec<char> = at_once[from].drain(split..).collect();
+        at_once[to].extend(moved);
+    }
+    let tops = |stacks: &[Vec<char>]| stacks.iter().map(|s| s.last().unwrap()).collect::<String>();
+    Generated {
+        input,
+        part1: tops(&one_by_one),
+        part2: tops(&at_once),
+    }
+}
+
+/// A datastream with planted start-of-packet and start-of-message markers.
+fn day06(rng: &mut Rng, size: usize) -> Generated {
+    let mut letters = LETTERS.to_vec();
+    rng.shuffle(&mut letters);
+    let mut stream: Vec<u8> = vec![];
+
+    // With only three letters no four characters can be all different, so
+    // the first packet marker is the fourth letter after them.
+    let mut first = letters[..3].to_vec();
+    stream.extend((0..rng.range(0, size / 4)).map(|_| rng.choose(&letters[..3])));
+    rng.shuffle(&mut first);
+    stream.extend(first);
+    stream.push(letters[3]);
+    let part1 = stream.len();
+
+    // Likewise up to the message marker only thirteen letters are used.
+    let mut last = letters[..13].to_vec();
+    stream.extend((0..rng.range(0, size / 4)).map(|_| rng.choose(&letters[..13])));
+    rng.shuffle(&mut last);
+    stream.extend(last);
+    stream.push(letters[13]);
+    let part2 = stream.len();
+
+    stream.extend((0..size / 2).map(|_| rng.choose(LETTERS)));
+    Generated {
+        input: format!("{}\n", String::from_utf8(stream).unwrap()),
+        part1: part1.to_string(),
+        part2: part2.to_string(),
+    }
+}
+
+struct Dir {
+    name: String,
+    files: Vec<(String, usize)>,
+    dirs: Vec<usize>,
+}
+
+/// Picks a name that is not used yet in one directory.
+fn fresh_name(rng: &mut Rng, used: &mut HashSet<String>, extension: bool) -> String {
+    loop {
+        let mut name: String = (0..rng.range(1, 8)).map(|_| rng.choose(LETTERS) as char).collect();
+        if extension && rng.below(2) == 0 {
+            name.push('.');
+            name.extend((0..3).map(|_| rng.choose(LETTERS) as char));
+        }
+        if used.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+fn write_session(dirs: &[Dir], dir: usize, session: &mut String) {
+    session.push_str("$ ls\n");
+    for &child in &dirs[dir].dirs {
+        writeln!(session, "dir {}", dirs[child].name).unwrap();
+    }
+    for (name, size) in &dirs[dir].files {
+        writeln!(session, "{} {}", size, name).unwrap();
+    }
+    for &child in &dirs[dir].dirs {
+        writeln!(session, "$ cd {}", dirs[child].name).unwrap();
+        write_session(dirs, child, session);
+        session.push_str("$ cd ..\n");
+    }
+}
+
+/// A terminal session exploring a random directory tree depth-first.
+fn day07(rng: &mut Rng, size: usize) -> Generated {
+    let mut dirs = vec![Dir { name: "/".into(), files: vec![], dirs: vec![] }];
+    let mut parents = vec![0];
+    let mut names = vec![HashSet::new()];
+    for i in 1..size.max(1) {
+        let parent = rng.below(i);
+        let name = fresh_name(rng, &mut names[parent], false);
+        dirs.push(Dir { name, files: vec![], dirs: vec![] });
+        dirs[parent].dirs.push(i);
+        parents.push(parent);
+        names.push(HashSet::new());
+    }
+    for (dir, used) in dirs.iter_mut().zip(&mut names) {
+        for _ in 0..rng.range(1, 4) {
+            let name = fresh_name(rng, used, true);
+            dir.files.push((name, rng.range(1, 300_000)));
+        }
+    }
+
+    // Directories are created after their parent, so walking backwards adds
+    // every subdirectory before its parent is added to the grandparent.
+    let mut sizes: Vec<usize> = dirs.iter().map(|d| d.files.iter().map(|(_, s)| s).sum()).collect();
+    for i in (1..dirs.len()).rev() {
+        sizes[parents[i]] += sizes[i];
+    }
+
+    let mut input = String::from("$ cd /\n");
+    write_session(&dirs, 0, &mut input);
+    let must_free = 30_000_000usize.saturating_sub(70_000_000usize.saturating_sub(sizes[0]));
+    Generated {
+        input,
+        part1: sizes.iter().filter(|&&s| s <= 100_000).sum::<usize>().to_string(),
+        part2: sizes.iter().filter(|&&s| s >= must_free).min().unwrap().to_string(),
+    }
+}
+
+/// A square grid of tree heights.
+fn day08(rng: &mut Rng, size: usize) -> Generated {
+    let n = size.max(1);
+    let grid: Vec<Vec<u8>> = (0..n).map(|_| (0..n).map(|_| rng.below(10) as u8).collect()).collect();
+    let mut input = String::new();
+    for row in &grid {
+        let line: String = row.iter().map(|h| (b'0' + h) as char).collect();
+        writeln!(input, "{}", line).unwrap();
+    }
+
+    let (mut visible, mut best) = (0, 0);
+    for i in 0..n {
+        for j in 0..n {
+            let h = grid[i][j];
+            let lines: [Vec<u8>; 4] = [
+                (0..j).rev().map(|k| grid[i][k]).collect(),
+                (j + 1..n).map(|k| grid[i][k]).collect(),
+                (0..i).rev().map(|k| grid[k][j]).collect(),
+                (i + 1..n).map(|k| grid[k][j]).collect(),
+            ];
+            if lines.iter().any(|line| line.iter().all(|&t| t < h)) {
+                visible += 1;
+            }
+            let score: usize = lines
+                .iter()
+                .map(|line| line.iter().position(|&t| t >= h).map_or(line.len(), |p| p + 1))
+                .product();
+            best = best.max(score);
+        }
+    }
+    Generated {
+        input,
+        part1: visible.to_string(),
+        part2: best.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::{self, Part};
+
+    #[test]
+    fn test_generated_answers() {
+        for generator in &GENERATORS {
+            let day = runner::find(generator.day).unwrap();
+            for seed in 0..20 {
+                let generated = generate(generator.day, seed, 40).unwrap();
+                let report = (day.run)(&generated.input, &[Part::One, Part::Two])
+                    .unwrap_or_else(|e| panic!("day {} seed {}: {}", generator.day, seed, e));
+                assert_eq!(
+                    (report.answers[0].value.as_deref().unwrap(), report.answers[1].value.as_deref().unwrap()),
+                    (generated.part1.as_str(), generated.part2.as_str()),
+                    "day {} seed {}",
+                    generator.day,
+                    seed
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_same_seed_same_input() {
+        let a = generate(7, 42, 30).unwrap();
+        let b = generate(7, 42, 30).unwrap();
+        assert_eq!(a.input, b.input);
+        assert_ne!(a.input, generate(7, 43, 30).unwrap().input);
+    }
+}
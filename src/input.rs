@@ -0,0 +1,68 @@
+use std::env;
+use std::io::Read;
+use std::path::PathBuf;
+
+use crate::error::AocError;
+
+/// Environment variable overriding the directory holding `dayNN.txt` inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Source {
+    Stdin,
+    Path(PathBuf),
+}
+
+impl Source {
+    /// `-` means stdin, anything else is a path; without an argument the
+    /// day's file in the input directory is used.
+    pub fn new(arg: Option<&str>, day: u8) -> Source {
+        match arg {
+            Some("-") => Source::Stdin,
+            Some(path) => Source::Path(path.into()),
+            None => Source::Path(default_path(day)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, AocError> {
+        match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|source| AocError::Io {
+                        path: "<stdin>".into(),
+                        source,
+                    })?;
+                Ok(input)
+            }
+            Source::Path(path) => std::fs::read_to_string(path).map_err(|source| AocError::Io {
+                path: path.display().to_string(),
+                source,
+            }),
+        }
+    }
+}
+
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR).map_or_else(|| PathBuf::from("input"), PathBuf::from)
+}
+
+pub fn default_path(day: u8) -> PathBuf {
+    input_dir().join(format!("day{:02}.txt", day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_from_argument() {
+        assert_eq!(Source::new(Some("-"), 1), Source::Stdin);
+        assert_eq!(
+            Source::new(Some("tests/day01.txt"), 1),
+            Source::Path("tests/day01.txt".into())
+        );
+        assert!(matches!(Source::new(None, 7), Source::Path(p) if p.ends_with("day07.txt")));
+    }
+}
@@ -0,0 +1,17 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+
+pub mod answers;
+pub mod bench;
+pub mod error;
+pub mod format;
+pub mod generate;
+pub mod input;
+pub mod runner;
+pub mod solution;
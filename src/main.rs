@@ -1,3 +1,409 @@
-fn main() {
-    println!("Hello, world!");
+use advent_of_code_2022::answers::{self, Answers, Status};
+use advent_of_code_2022::bench::{self, Row, VARIANTS};
+use advent_of_code_2022::day01::{self, rebalance::Method, Day01};
+use advent_of_code_2022::day02::{cipher, counter, equilibrium, game::Game, moves::Move, round::ParseMode};
+use advent_of_code_2022::day02::{strategy::Guide, tournament, trace, Day02};
+use advent_of_code_2022::error::AocError;
+use advent_of_code_2022::format::{self, Format, Outcome};
+use advent_of_code_2022::generate;
+use advent_of_code_2022::input::{self, Source};
+use advent_of_code_2022::runner::{self, Day, Part, DAYS};
+use advent_of_code_2022::solution::Solution;
+use std::{env, path::Path, process::{self, ExitCode}, time::Duration};
+
+fn usage() -> ! {
+    eprintln!("Usage: advent-of-code-2022 run <day|all> [a|b] [--input <path|->] [--format plain|json|tap]");
+    eprintln!("       advent-of-code-2022 run 2 [a|b] --trace [--input <path|->] [--format plain|json]");
+    eprintln!("       advent-of-code-2022 verify [--answers <path>]");
+    eprintln!("       advent-of-code-2022 bench <day|all> [-n <iterations>] [--input <path|->]");
+    eprintln!("                                 [--json <path>] [--csv <path>]");
+    eprintln!("       advent-of-code-2022 stats [--input <path|->] [--format plain|json]");
+    eprintln!("       advent-of-code-2022 rebalance [--input <path|->] [--method exact|greedy|kk]");
+    eprintln!("                                     [--format plain|json]");
+    eprintln!("       advent-of-code-2022 cipher [--input <path|->] [--format plain|json]");
+    eprintln!("       advent-of-code-2022 counter [--max-wins <n>] [--draws <n>] [--min-score <n>]");
+    eprintln!("                                   [--input <path|->] [--format plain|json]");
+    eprintln!("       advent-of-code-2022 equilibrium [--game rps|rpsls|rps7|rps15] [--input <path|->]");
+    eprintln!("                                       [--format plain|json]");
+    eprintln!("       advent-of-code-2022 tournament [--rounds <n>] [--seed <n>] [--input <guide>]");
+    eprintln!("       advent-of-code-2022 generate <day> [--seed <n>] [--size <n>]");
+    eprintln!();
+    eprintln!("Inputs default to $AOC_INPUT_DIR/dayNN.txt, or input/dayNN.txt if unset.");
+    eprintln!("`generate` prints a random input and writes its expected answers to stderr.");
+    process::exit(2);
+}
+
+/// Removes `<name> <value>` from the arguments, returning the value.
+fn take_option(args: &mut Vec<&str>, names: &[&str]) -> Option<String> {
+    let i = args.iter().position(|a| names.contains(a))?;
+    if i + 1 >= args.len() {
+        usage();
+    }
+    let value = args.remove(i + 1).to_string();
+    args.remove(i);
+    Some(value)
+}
+
+/// Parses the input once and reports each part separately, so that a failing
+/// part does not hide the other.
+fn solve(day: &Day, parts: &[Part], source: Option<&str>) -> Vec<Outcome> {
+    let report = Source::new(source, day.day).read().and_then(|input| (day.run)(&input, parts));
+    match report {
+        Ok(report) => report
+            .answers
+            .into_iter()
+            .map(|answer| Outcome {
+                day: day.day,
+                part: answer.part,
+                answer: answer.value.map_err(|e| e.to_string()),
+                parse_time: report.parse_time,
+                elapsed: answer.elapsed,
+            })
+            .collect(),
+        Err(e) => parts
+            .iter()
+            .map(|&part| Outcome {
+                day: day.day,
+                part,
+                answer: Err(e.to_string()),
+                parse_time: Duration::ZERO,
+                elapsed: Duration::ZERO,
+            })
+            .collect(),
+    }
+}
+
+/// Removes a flag from the arguments, returning whether it was there.
+fn take_flag(args: &mut Vec<&str>, name: &str) -> bool {
+    let found = args.contains(&name);
+    args.retain(|a| *a != name);
+    found
+}
+
+/// Prints how every round of the day 2 guide is scored.
+fn cmd_trace(parts: &[Part], source: Option<String>, output: Format) -> Result<(), AocError> {
+    let input = Source::new(source.as_deref(), 2).read()?;
+    for &part in parts {
+        let mode = match part {
+            Part::One => ParseMode::Move,
+            Part::Two => ParseMode::Outcome,
+        };
+        let steps = trace::trace(&Guide::parse(&input, mode)?.player_rounds());
+        match output {
+            Format::Plain => print!("Part {}\n{}\n", part, trace::to_table(&steps)),
+            Format::Json => print!("{}", trace::to_json_lines(&steps)),
+            Format::Tap => usage(),
+        }
+    }
+    Ok(())
+}
+
+/// Days selected by a `<day|all>` argument.
+fn select_days(day: &str) -> Vec<&'static Day> {
+    match day {
+        "all" => DAYS.iter().collect(),
+        day => runner::find(day.parse().unwrap_or_else(|_| usage()))
+            .into_iter()
+            .collect(),
+    }
+}
+
+fn cmd_run(args: &[&str], source: Option<String>, output: Format, trace: bool) -> ExitCode {
+    let both = [Part::One, Part::Two];
+    if args.first() == Some(&"all") && source.is_some() {
+        usage();
+    }
+    let (selected, parts): (Vec<&Day>, Vec<Part>) = match args {
+        [day] => (select_days(day), both.to_vec()),
+        [day, part] => {
+            let part: Part = part.parse().unwrap_or_else(|_| usage());
+            (select_days(day), vec![part])
+        }
+        _ => usage(),
+    };
+
+    if trace {
+        return match selected[..] {
+            [day] if day.day == 2 => runner::report(cmd_trace(&parts, source, output)),
+            _ => usage(),
+        };
+    }
+
+    if selected.is_empty() {
+        eprintln!("No solver registered for {}", args.join(" "));
+        return ExitCode::FAILURE;
+    }
+
+    let outcomes: Vec<Outcome> = selected
+        .into_iter()
+        .flat_map(|day| solve(day, &parts, source.as_deref()))
+        .collect();
+    print!("{}", format::render(output, &outcomes));
+    if outcomes.iter().all(|o| o.answer.is_ok()) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn cmd_verify(answers_path: &str) -> Result<bool, AocError> {
+    let answers = Answers::load(Path::new(answers_path))?;
+    let checks = answers::verify(&answers, &input::input_dir())?;
+
+    let input_width = checks.iter().map(|c| c.input.len()).max().unwrap_or(0).max(5);
+    println!(
+        "{:>3}  {:>4}  {:<input_width$}  {:<7}  {:<16}  {:<16}",
+        "Day", "Part", "Input", "Status", "Expected", "Actual"
+    );
+    for check in &checks {
+        let (expected, actual) = match &check.status {
+            Status::Pass => ("", ""),
+            Status::Fail { expected, actual } => (expected.as_str(), actual.as_str()),
+            Status::MissingAnswer { actual } => ("?", actual.as_str()),
+            Status::MissingInput { expected } => (expected.as_str(), "?"),
+            Status::Error(e) => ("", e.as_str()),
+        };
+        println!(
+            "{:>3}  {:>4}  {:<input_width$}  {:<7}  {:<16}  {:<16}",
+            check.day, check.part, check.input, check.status.to_string(), expected, actual
+        );
+    }
+
+    let count = |f: fn(&Status) -> bool| checks.iter().filter(|c| f(&c.status)).count();
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        count(|s| *s == Status::Pass),
+        count(|s| matches!(s, Status::Fail { .. } | Status::Error(_))),
+        count(|s| matches!(s, Status::MissingAnswer { .. } | Status::MissingInput { .. })),
+    );
+    Ok(checks.iter().all(|c| !c.failed()))
+}
+
+fn format_duration(duration: std::time::Duration) -> String {
+    format!("{:.1?}", duration)
+}
+
+fn cmd_bench(
+    day: &str,
+    iterations: usize,
+    source: Option<String>,
+    json: Option<String>,
+    csv: Option<String>,
+) -> Result<(), AocError> {
+    if day == "all" && source.is_some() {
+        usage();
+    }
+    let mut rows: Vec<Row> = vec![];
+    for day in select_days(day) {
+        let input = match Source::new(source.as_deref(), day.day).read() {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Skipping day {}: {}", day.day, e);
+                continue;
+            }
+        };
+        let timings = (day.bench)(&input, iterations)?;
+        rows.extend(bench::rows(day.day, iterations, &timings));
+        for variant in VARIANTS.iter().filter(|v| v.day == day.day) {
+            rows.push(Row {
+                day: day.day,
+                stage: variant.name.to_string(),
+                iterations,
+                stats: (variant.bench)(&input, iterations)?,
+            });
+        }
+    }
+
+    println!(
+        "{:>3}  {:<24}  {:>10}  {:>10}  {:>10}",
+        "Day", "Stage", "Min", "Median", "Max"
+    );
+    for row in &rows {
+        println!(
+            "{:>3}  {:<24}  {:>10}  {:>10}  {:>10}",
+            row.day,
+            row.stage,
+            format_duration(row.stats.min),
+            format_duration(row.stats.median),
+            format_duration(row.stats.max)
+        );
+    }
+
+    let write = |path: &str, contents: String| {
+        std::fs::write(path, contents).map_err(|source| AocError::Io {
+            path: path.to_string(),
+            source,
+        })
+    };
+    if let Some(path) = json {
+        write(&path, bench::to_json(&rows))?;
+    }
+    if let Some(path) = csv {
+        write(&path, bench::to_csv(&rows))?;
+    }
+    Ok(())
+}
+
+/// Statistics of the day 1 calorie inventory.
+fn cmd_stats(source: Option<String>, output: Format) -> Result<(), AocError> {
+    let input = Source::new(source.as_deref(), 1).read()?;
+    let stats = day01::stats::stats(&Day01::parse(&input)?)?;
+    match output {
+        Format::Plain => print!("{}", stats.to_table()),
+        Format::Json => print!("{}", stats.to_json()),
+        Format::Tap => usage(),
+    }
+    Ok(())
+}
+
+/// Plans how the elves of day 1 can share their food more evenly.
+fn cmd_rebalance(source: Option<String>, method: Option<Method>, output: Format) -> Result<(), AocError> {
+    let input = Source::new(source.as_deref(), 1).read()?;
+    let plan = day01::rebalance::plan(&Day01::parse(&input)?, method)?;
+    match output {
+        Format::Plain => print!("{}", plan.to_table()),
+        Format::Json => print!("{}", plan.to_json()),
+        Format::Tap => usage(),
+    }
+    Ok(())
+}
+
+/// Scores the day 2 guide under every reading of its second column.
+fn cmd_cipher(source: Option<String>, output: Format) -> Result<(), AocError> {
+    let input = Source::new(source.as_deref(), 2).read()?;
+    let (moves, outcomes) = Day02::strategies(&input)?;
+    let analysis = cipher::analyse(&moves, &outcomes);
+    match output {
+        Format::Plain => print!("{}", analysis.to_table()),
+        Format::Json => print!("{}", analysis.to_json()),
+        Format::Tap => usage(),
+    }
+    Ok(())
+}
+
+/// Plays the best strategy against the opponent column of the guide.
+fn cmd_counter(constraints: counter::Constraints, source: Option<String>, output: Format) -> Result<(), AocError> {
+    let input = Source::new(source.as_deref(), 2).read()?;
+    let opponents: Vec<_> = Guide::parse(&input, ParseMode::Move)?.player_rounds().iter().map(|r| r.opponent).collect();
+    let steps = trace::trace(&counter::solve(&opponents, constraints)?.rounds);
+    match output {
+        Format::Plain => print!("{}", trace::to_table(&steps)),
+        Format::Json => print!("{}", trace::to_json_lines(&steps)),
+        Format::Tap => usage(),
+    }
+    Ok(())
+}
+
+/// Solves the game and compares both readings of the guide with its value.
+/// Without a game, the puzzle's own rules and parsing are used.
+fn cmd_equilibrium(game: Option<String>, source: Option<String>, output: Format) -> Result<(), AocError> {
+    let input = Source::new(source.as_deref(), 2).read()?;
+    let modes = [ParseMode::Move, ParseMode::Outcome];
+    let (payoffs, rounds) = match game.as_deref() {
+        None => {
+            let index = |m: Move| Move::ALL.iter().position(|&other| other == m).unwrap();
+            let mut rounds = vec![];
+            for mode in modes {
+                let guide = Guide::parse(&input, mode)?.player_rounds();
+                rounds.push(guide.iter().map(|r| (index(r.opponent), index(r.player))).collect());
+            }
+            (equilibrium::Payoffs::puzzle(), rounds)
+        }
+        Some(name) => {
+            let game = match name {
+                "rps" => Game::rps(),
+                "rpsls" => Game::rpsls(),
+                "rps7" => Game::rps7(),
+                "rps15" => Game::rps15(),
+                _ => usage(),
+            };
+            let rounds = modes.iter().map(|&mode| game.parse_guide(&input, mode)).collect::<Result<_, _>>()?;
+            (equilibrium::Payoffs::of_game(&game), rounds)
+        }
+    };
+    let solved = equilibrium::solve(&payoffs);
+    let comparisons: Vec<_> = modes
+        .iter()
+        .zip(&rounds)
+        .map(|(&mode, rounds): (_, &Vec<_>)| equilibrium::compare(&payoffs, mode, rounds))
+        .collect();
+    match output {
+        Format::Plain => print!("{}", equilibrium::to_table(&payoffs, &solved, &comparisons)),
+        Format::Json => print!("{}", equilibrium::to_json(&payoffs, &solved, &comparisons)),
+        Format::Tap => usage(),
+    }
+    Ok(())
+}
+
+/// Plays the rock-paper-scissors bots against each other. The bot replaying
+/// the day 2 guide only takes part if the guide can be read.
+fn cmd_tournament(rounds: usize, seed: u64, source: Option<String>) -> Result<(), AocError> {
+    let guide = match Source::new(source.as_deref(), 2).read() {
+        Ok(input) => Some(Guide::parse(&input, ParseMode::Move)?.player_rounds()),
+        Err(e) if source.is_some() => return Err(e),
+        Err(_) => None,
+    };
+    let table = tournament::round_robin(&tournament::entrants(seed, guide), rounds);
+    print!("{}", tournament::to_table(&table));
+    Ok(())
+}
+
+fn cmd_generate(day: &str, seed: u64, size: usize) -> ExitCode {
+    let day: u8 = day.parse().unwrap_or_else(|_| usage());
+    let Some(generated) = generate::generate(day, seed, size) else {
+        eprintln!("No generator registered for day {}", day);
+        return ExitCode::FAILURE;
+    };
+    print!("{}", generated.input);
+    eprintln!("part 1: {}", generated.part1);
+    eprintln!("part 2: {}", generated.part2);
+    ExitCode::SUCCESS
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let source = take_option(&mut args, &["--input", "-i"]);
+    let trace = take_flag(&mut args, "--trace");
+    let answers_path = take_option(&mut args, &["--answers", "-a"]);
+    let iterations: usize = take_option(&mut args, &["--iterations", "-n"])
+        .map_or(10, |n| n.parse().unwrap_or_else(|_| usage()));
+    let json = take_option(&mut args, &["--json"]);
+    let csv = take_option(&mut args, &["--csv"]);
+    let output: Format = take_option(&mut args, &["--format", "-f"])
+        .map_or(Format::Plain, |f| f.parse().unwrap_or_else(|_| usage()));
+    let method: Option<Method> = take_option(&mut args, &["--method"])
+        .map(|m| m.parse().unwrap_or_else(|_| usage()));
+    let rounds: usize = take_option(&mut args, &["--rounds"])
+        .map_or(1000, |n| n.parse().unwrap_or_else(|_| usage()));
+    let seed: u64 = take_option(&mut args, &["--seed"])
+        .map_or(0, |n| n.parse().unwrap_or_else(|_| usage()));
+    let size: usize = take_option(&mut args, &["--size"])
+        .map_or(100, |n| n.parse().unwrap_or_else(|_| usage()));
+    let game = take_option(&mut args, &["--game"]);
+    let constraints = counter::Constraints {
+        max_wins: take_option(&mut args, &["--max-wins"]).map(|n| n.parse().unwrap_or_else(|_| usage())),
+        draws: take_option(&mut args, &["--draws"]).map(|n| n.parse().unwrap_or_else(|_| usage())),
+        min_score: take_option(&mut args, &["--min-score"]).map(|n| n.parse().unwrap_or_else(|_| usage())),
+    };
+
+    match args.as_slice() {
+        ["run", rest @ ..] => cmd_run(rest, source, output, trace),
+        ["verify"] if source.is_none() => {
+            match cmd_verify(answers_path.as_deref().unwrap_or(answers::ANSWERS_PATH)) {
+                Ok(true) => ExitCode::SUCCESS,
+                Ok(false) => ExitCode::FAILURE,
+                Err(e) => runner::report::<()>(Err(e)),
+            }
+        }
+        ["stats"] => runner::report(cmd_stats(source, output)),
+        ["rebalance"] => runner::report(cmd_rebalance(source, method, output)),
+        ["cipher"] => runner::report(cmd_cipher(source, output)),
+        ["counter"] => runner::report(cmd_counter(constraints, source, output)),
+        ["equilibrium"] => runner::report(cmd_equilibrium(game, source, output)),
+        ["tournament"] => runner::report(cmd_tournament(rounds, seed, source)),
+        ["generate", day] => cmd_generate(day, seed, size),
+        ["bench", day] => runner::report(cmd_bench(day, iterations, source, json, csv)),
+        _ => usage(),
+    }
 }
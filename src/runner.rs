@@ -0,0 +1,147 @@
+use std::fmt;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use crate::bench::{self, Timings};
+use crate::error::AocError;
+use crate::input::Source;
+use crate::solution::Solution;
+use crate::*;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+impl std::str::FromStr for Part {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" | "1" => Ok(Part::One),
+            "b" | "2" => Ok(Part::Two),
+            _ => Err(s.to_string()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Answer {
+    pub part: Part,
+    /// Each part fails on its own, so that an error does not hide the other.
+    pub value: Result<String, AocError>,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug)]
+pub struct Report {
+    pub parse_time: Duration,
+    pub answers: Vec<Answer>,
+}
+
+/// Parses the input once and solves the requested parts on the parsed model.
+pub fn run<S: Solution>(input: &str, parts: &[Part]) -> Result<Report, AocError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let value = match part {
+                Part::One => S::part1(&parsed).map(|v| v.to_string()),
+                Part::Two => S::part2(&parsed).map(|v| v.to_string()),
+            };
+            Answer {
+                part,
+                value,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(Report { parse_time, answers })
+}
+
+/// Prints the error, if any, and turns it into a failing exit code.
+pub fn report<T>(result: Result<T, AocError>) -> ExitCode {
+    match result {
+        Ok(_) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Entry point of the per-day binaries, which take the input source as their
+/// only argument and print one answer per line.
+pub fn solve<S: Solution>(day: u8, parts: &[Part]) -> ExitCode {
+    let arg = std::env::args().nth(1);
+    report(Source::new(arg.as_deref(), day).read().and_then(|input| {
+        let report = run::<S>(&input, parts)?;
+        for answer in report.answers {
+            println!("{}", answer.value?);
+        }
+        Ok(())
+    }))
+}
+
+pub struct Day {
+    pub day: u8,
+    pub run: fn(&str, &[Part]) -> Result<Report, AocError>,
+    pub bench: fn(&str, usize) -> Result<Timings, AocError>,
+}
+
+pub const DAYS: [Day; 8] = [
+    Day { day: 1, run: run::<day01::Day01>, bench: bench::bench::<day01::Day01> },
+    Day { day: 2, run: run::<day02::Day02>, bench: bench::bench::<day02::Day02> },
+    Day { day: 3, run: run::<day03::Day03>, bench: bench::bench::<day03::Day03> },
+    Day { day: 4, run: run::<day04::Day04>, bench: bench::bench::<day04::Day04> },
+    Day { day: 5, run: run::<day05::Day05>, bench: bench::bench::<day05::Day05> },
+    Day { day: 6, run: run::<day06::Day06>, bench: bench::bench::<day06::Day06> },
+    Day { day: 7, run: run::<day07::Day07>, bench: bench::bench::<day07::Day07> },
+    Day { day: 8, run: run::<day08::Day08>, bench: bench::bench::<day08::Day08> },
+];
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_parses_once_for_both_parts() {
+        let report = run::<day06::Day06>(day06::EXAMPLE, &[Part::One, Part::Two]).unwrap();
+        let values: Vec<&str> = report.answers.iter().map(|a| a.value.as_deref().unwrap()).collect();
+        assert_eq!(values, vec!["7", "19"]);
+    }
+
+    #[test]
+    fn test_run_reports_each_part() {
+        // Two rucksacks cannot be split in groups of three, but part 1 still
+        // has an answer.
+        let report = run::<day03::Day03>("abca\ndefd\n", &[Part::One, Part::Two]).unwrap();
+        assert_eq!(report.answers[0].value.as_deref().unwrap(), (1 + 4).to_string());
+        assert!(report.answers[1].value.is_err());
+    }
+
+    #[test]
+    fn test_parse_part() {
+        assert_eq!("a".parse(), Ok(Part::One));
+        assert_eq!("2".parse(), Ok(Part::Two));
+        assert!("c".parse::<Part>().is_err());
+    }
+}
@@ -0,0 +1,44 @@
+use std::fmt::Display;
+
+use crate::error::AocError;
+
+/// A day's puzzle, split into a parsing stage shared by both parts.
+pub trait Solution {
+    type Input;
+    type Output1: Display;
+    type Output2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError>;
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError>;
+    fn part2(input: &Self::Input) -> Result<Self::Output2, AocError>;
+}
+
+/// Parses `input` and checks both answers against the expected ones.
+#[track_caller]
+pub fn assert_answers<S: Solution>(input: &str, part1: impl Display, part2: impl Display) {
+    let parsed = S::parse(input).unwrap_or_else(|e| panic!("parse failed: {}", e));
+    let answer1 = S::part1(&parsed).unwrap_or_else(|e| panic!("part 1 failed: {}", e));
+    let answer2 = S::part2(&parsed).unwrap_or_else(|e| panic!("part 2 failed: {}", e));
+    assert_eq!(answer1.to_string(), part1.to_string(), "part 1");
+    assert_eq!(answer2.to_string(), part2.to_string(), "part 2");
+}
+
+/// Declares one test per example, checking both answers of a solution.
+///
+/// ```ignore
+/// example_tests!(Day06,
+///     example: EXAMPLE => (7, 19),
+///     second_example: "bvwbjplbgvbhsrlpgdmjqwftvncz" => (5, 23),
+/// );
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty, $($name:ident: $input:expr => ($part1:expr, $part2:expr)),+ $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                $crate::solution::assert_answers::<$solution>($input, $part1, $part2);
+            }
+        )+
+    };
+}